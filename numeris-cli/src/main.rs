@@ -1,75 +1,360 @@
 use std::io;
-use std::io::Write;
+use std::io::{BufRead, Write};
+use std::process;
 
 use ansi_term::{Colour::Cyan, Colour::Green, Colour::Red, Style};
-use clap::{
-    clap_app, crate_authors, crate_description, crate_name, crate_version, value_t, ArgMatches,
+use clap::{clap_app, crate_authors, crate_description, crate_name, crate_version, ArgMatches};
+use serde_json::json;
+
+use numeris::{
+    integer_to_roman, normalize, roman_table, roman_to_integer_with_suggestions,
+    ErrorWithSuggestions, RomanNumeralError, RomanTable, SpreadsheetForm,
 };
 
-use numeris::{integer_to_roman, roman_to_integer, RomanNumeralError};
+/// How the results of conversions are written.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Format {
+    /// Labelled text, with results written to stdout and errors to stderr.
+    Text,
+    /// Text without labels, with results written to stdout and errors to stderr.
+    Bare,
+    /// A JSON object on its own line for each value, with both results and errors written to
+    /// stdout.
+    Json,
+    /// A row of comma-separated values for each value, after a header row, with both results and
+    /// errors written to stdout.
+    Csv,
+    /// A row of tab-separated values for each value, after a header row, with both results and
+    /// errors written to stdout.
+    Tsv,
+}
+
+impl Format {
+    /// The character that separates the columns of each row, for the delimited formats.
+    fn delimiter(self) -> Option<char> {
+        match self {
+            Format::Csv => Some(','),
+            Format::Tsv => Some('\t'),
+            _ => None,
+        }
+    }
+}
+
+/// The columns of each row of the delimited formats.
+const COLUMNS: [&str; 4] = ["input", "kind", "result", "error"];
+
+/// What is done with each value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Command {
+    /// Converts integers into Roman numerals.
+    ToRoman,
+    /// Converts Roman numerals into integers.
+    ToInt,
+    /// Converts either, detected from the value: anything containing a digit is taken to be an
+    /// integer.
+    Detect,
+    /// Checks that Roman numerals are valid and canonical.
+    Validate,
+    /// Converts Roman numerals in any accepted form into their canonical form.
+    Normalize,
+}
+
+impl Command {
+    fn of(val: &str) -> Command {
+        if val.chars().any(|ch| ch.is_ascii_digit()) {
+            Command::ToRoman
+        } else {
+            Command::ToInt
+        }
+    }
+
+    /// The kind of value that this command accepts.
+    fn kind(self) -> &'static str {
+        match self {
+            Command::ToRoman => "integer",
+            Command::Detect => "value",
+            _ => "roman",
+        }
+    }
+
+    /// The result of this command for `val`, or a message explaining why it failed.
+    fn apply(self, val: &str) -> Result<String, String> {
+        match self {
+            Command::ToRoman => match val.parse() {
+                Ok(i) => to_roman(i),
+                Err(_) => Err(format!("{} is not an integer", val)),
+            },
+            Command::ToInt => to_integer(val),
+            Command::Detect => Command::of(val).apply(val),
+            Command::Validate => roman_to_integer_with_suggestions(val)
+                .map(|_| format!("{} is valid", val))
+                .map_err(|e| numeral_error(val, e)),
+            Command::Normalize => {
+                normalize(val).map_err(|e| numeral_error(val, ErrorWithSuggestions::new(val, e)))
+            }
+        }
+    }
+}
 
 fn main() {
-    let args = app_args();
+    let app = app_args();
+    let args = app.subcommand().1.unwrap_or(&app);
     let debug = args.is_present("debug");
-    let bare = args.is_present("bare");
-    if args.is_present("integer") {
-        let i = value_t!(args.value_of("integer"), u32).unwrap_or_else(|e| e.exit());
+    let format = match args.value_of("format") {
+        Some("json") => Format::Json,
+        Some("csv") => Format::Csv,
+        Some("tsv") => Format::Tsv,
+        _ if args.is_present("bare") => Format::Bare,
+        _ => Format::Text,
+    };
+    if let Some(delimiter) = format.delimiter() {
+        print_row(&COLUMNS, delimiter, io::stdout()).unwrap();
+    }
+    let command = match app.subcommand_name() {
+        Some("to-roman") => Command::ToRoman,
+        Some("to-int") => Command::ToInt,
+        Some("validate") => Command::Validate,
+        Some("normalize") => Command::Normalize,
+        Some("table") => return print_range(args, format, debug),
+        _ => Command::Detect,
+    };
+    let values: Vec<&str> = args.values_of("values").map_or_else(Vec::new, Iterator::collect);
+    let succeeded = if args.is_present("stdin") || values.is_empty() {
         if debug {
-            print_debug(String::from("integer"), i.to_string());
+            print_debug(&format!("{}s", command.kind()), "stdin", io::stderr());
         }
-        print_roman_numeral(i, bare, &mut io::stdout(), &mut io::stderr());
+        let stdin = io::stdin();
+        print_lines(command, stdin.lock(), format, &mut io::stdout(), &mut io::stderr())
     } else {
-        let rn = args.value_of("roman").unwrap();
-        if debug {
-            print_debug(String::from("roman"), String::from(rn))
+        values.into_iter().fold(true, |succeeded, val| {
+            if debug {
+                let detected = if command == Command::Detect { Command::of(val) } else { command };
+                print_debug(detected.kind(), val, io::stderr())
+            }
+            print_value(command, val, format, &mut io::stdout(), &mut io::stderr()) && succeeded
+        })
+    };
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// Prints the table of numerals for the `table` subcommand.
+fn print_range(args: &ArgMatches, format: Format, debug: bool) {
+    let (start, end) = parse_range(args.value_of("range").unwrap()).unwrap();
+    if debug {
+        print_debug("range", &format!("{}..={}", start, end), io::stderr());
+    }
+    let mut table = roman_table(start..=end).unwrap();
+    if let Some(form) = args.value_of("form") {
+        table = table.form(form.parse().ok().and_then(SpreadsheetForm::new).unwrap());
+    }
+    if args.is_present("lowercase") {
+        table = table.lowercase();
+    }
+    print_table(table, format, &mut io::stdout(), &mut io::stderr());
+}
+
+/// Applies `command` to each line of `lines`, skipping blank lines, and carrying on past any that
+/// fail. Returns whether every line succeeded.
+fn print_lines(
+    command: Command,
+    lines: impl BufRead,
+    format: Format,
+    mut out: impl Write,
+    mut err: impl Write,
+) -> bool {
+    let mut succeeded = true;
+    for line in lines.lines().map_while(|line| line.ok()) {
+        let val = line.trim();
+        if !val.is_empty() {
+            succeeded &= print_value(command, val, format, &mut out, &mut err);
         }
-        print_integer(rn, bare, &mut io::stdout(), &mut io::stderr());
+    }
+    succeeded
+}
+
+/// Applies `command` to `val`, and writes the result. Returns whether it succeeded.
+fn print_value(
+    command: Command,
+    val: &str,
+    format: Format,
+    out: impl Write,
+    err: impl Write,
+) -> bool {
+    match command {
+        Command::Detect => print_value(Command::of(val), val, format, out, err),
+        _ => print_result(val, command, command.apply(val), format, out, err),
     }
 }
 
-fn print_roman_numeral(val: u32, bare: bool, mut out: impl Write, mut err: impl Write) {
-    match integer_to_roman(val) {
-        Ok(rn) => writeln!(out, "{}{}", result_prefix(bare), Green.paint(rn)),
-        Err(e) => {
-            let msg = match e {
-                RomanNumeralError::ValueTooLarge(n) => format!("{} is too large", n),
-                RomanNumeralError::ValueTooSmall(n) => format!("{} is too small", n),
-                _ => String::from("Well, this is awkward"),
-            };
-            writeln!(err, "{}{}", error_prefix(bare), Red.paint(msg))
+/// Writes each value in `table` with its numeral: as a table of two aligned columns for the text
+/// formats, or as a row or object for each value for the other formats.
+fn print_table(table: RomanTable, format: Format, mut out: impl Write, mut err: impl Write) {
+    let width = table.clone().next_back().map_or(0, |(val, _)| val.to_string().len());
+    for (val, numeral) in table {
+        if let Format::Text | Format::Bare = format {
+            writeln!(out, "{:>width$}  {}", val, Green.paint(numeral), width = width).unwrap();
+        } else {
+            let row = Ok(numeral);
+            print_result(&val.to_string(), Command::ToRoman, row, format, &mut out, &mut err);
         }
     }
-    .unwrap();
 }
 
-fn print_integer(val: &str, bare: bool, mut out: impl Write, mut err: impl Write) {
-    match roman_to_integer(val) {
-        Ok(i) => writeln!(out, "{}{}", result_prefix(bare), Green.paint(i.to_string())),
-        Err(e) => {
-            let msg = match e {
-                RomanNumeralError::Unparsable(v) => format!("{} is not a valid Roman numeral", v),
-                RomanNumeralError::EmptyString => format!("No Roman numeral provided"),
-                _ => String::from("Well, this is awkward"),
-            };
-            writeln!(err, "{}{}", error_prefix(bare), Red.paint(msg))
+/// Parses a range of integers, written as `START:END`, `START..=END` or `START..END`, into its
+/// first and last values.
+fn parse_range(range: &str) -> Result<(u32, u32), String> {
+    let (start, end, inclusive) = if let Some((start, end)) = range.split_once("..=") {
+        (start, end, true)
+    } else if let Some((start, end)) = range.split_once("..") {
+        (start, end, false)
+    } else if let Some((start, end)) = range.split_once(':') {
+        (start, end, true)
+    } else {
+        return Err(format!("{} is not a range (e.g. 1:100 or 1..=100)", range));
+    };
+    let parse = |val: &str| {
+        val.trim().parse::<u32>().map_err(|_| format!("{} is not an integer", val.trim()))
+    };
+    let (start, end) = (parse(start)?, parse(end)?);
+    let end = if inclusive { end } else { end.saturating_sub(1) };
+    if start > end {
+        return Err(format!("{} is empty", range));
+    }
+    roman_table(start..=end).map(|_| (start, end)).map_err(|e| e.to_string())
+}
+
+fn validate_range(range: String) -> Result<(), String> {
+    parse_range(&range).map(|_| ())
+}
+
+/// Writes the result of applying `command` to `val`, or the reason that it failed. Returns whether
+/// it succeeded.
+fn print_result(
+    val: &str,
+    command: Command,
+    result: Result<String, String>,
+    format: Format,
+    mut out: impl Write,
+    mut err: impl Write,
+) -> bool {
+    let succeeded = result.is_ok();
+    if let Some(delimiter) = format.delimiter() {
+        let (converted, msg) = match &result {
+            Ok(converted) => (converted.as_str(), ""),
+            Err(msg) => ("", msg.as_str()),
+        };
+        print_row(&[val, command.kind(), converted, msg], delimiter, out)
+    } else if format == Format::Json {
+        let mut object = json!({ "input": val, "kind": command.kind() });
+        match result {
+            Ok(converted) => object["result"] = converted.into(),
+            Err(msg) => object["error"] = msg.into(),
+        }
+        writeln!(out, "{}", object)
+    } else {
+        let bare = format == Format::Bare;
+        match result {
+            Ok(converted) => writeln!(out, "{}{}", result_prefix(bare), Green.paint(converted)),
+            Err(msg) => writeln!(err, "{}{}", error_prefix(bare), Red.paint(msg)),
         }
     }
     .unwrap();
+    succeeded
+}
+
+/// Writes a row of a delimited format. Fields containing the delimiter are quoted for CSV, and
+/// tabs are replaced by spaces for TSV, as it has no way of quoting them.
+fn print_row(fields: &[&str], delimiter: char, mut out: impl Write) -> io::Result<()> {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|field| match delimiter {
+            '\t' => field.replace('\t', " "),
+            _ if field.contains(&[delimiter, '"', '\n', '\r'][..]) => {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            }
+            _ => field.to_string(),
+        })
+        .collect();
+    writeln!(out, "{}", fields.join(&delimiter.to_string()))
+}
+
+fn to_roman(val: u32) -> Result<String, String> {
+    integer_to_roman(val).map_err(|e| describe(&val.to_string(), e))
+}
+
+fn to_integer(val: &str) -> Result<String, String> {
+    roman_to_integer_with_suggestions(val).map(|i| i.to_string()).map_err(|e| numeral_error(val, e))
+}
+
+/// Describes an error in the numeral `val`, with suggestions for what may have been meant.
+fn numeral_error(val: &str, e: ErrorWithSuggestions) -> String {
+    match e.hint() {
+        Some(hint) => format!("{}; {}", describe(val, e.into_error()), hint),
+        None => describe(val, e.into_error()),
+    }
+}
+
+fn describe(val: &str, e: RomanNumeralError) -> String {
+    match e {
+        RomanNumeralError::ValueTooLarge(n) => format!("{} is too large", n),
+        RomanNumeralError::ValueTooSmall(n) => format!("{} is too small", n),
+        RomanNumeralError::InvalidCharacter { ch, pos } => format!(
+            "{} is not a valid Roman numeral ('{}' at position {} is not a symbol)",
+            val,
+            ch,
+            pos.char + 1
+        ),
+        RomanNumeralError::InvalidRepetition { symbol, count, .. } => {
+            format!("{} is not a valid Roman numeral ({} is repeated {} times)", val, symbol, count)
+        }
+        RomanNumeralError::OutOfOrder { found, expected_max, .. } => format!(
+            "{} is not a valid Roman numeral ({} found where at most {} was expected)",
+            val, found, expected_max
+        ),
+        RomanNumeralError::EmptyString => String::from("No Roman numeral provided"),
+        _ => String::from("Well, this is awkward"),
+    }
 }
 
 fn app_args() -> ArgMatches<'static> {
     clap_app!(numeris =>
-        (@group conversion +required =>
-            (@arg integer: -i --integer [NUMBER] "Convert the given integer value to a roman numeral")
-            (@arg roman: -r --roman [NUMERAL] "Convert the given roman numeral to an integer value")
+        (@arg values: [VALUE]... "Convert each of the given integers or roman numerals, depending on whether it contains digits (or each line of stdin)")
+        (@arg stdin: -s --stdin +global "Read the values from standard input, one per line")
+        (@arg debug: -d --debug +global "Debugging output")
+        (@arg bare: -b --bare +global "Only output the result")
+        (@arg format: -f --format +global +takes_value possible_values(&["text", "json", "csv", "tsv"]) "Output format: text (the default); json, with an object for each value; or csv or tsv, with a row for each value")
+        (@subcommand to_roman =>
+            (name: "to-roman")
+            (about: "Converts integers to roman numerals")
+            (@arg values: [NUMBER]... "The integers to convert (or each line of stdin)")
+        )
+        (@subcommand to_int =>
+            (name: "to-int")
+            (about: "Converts roman numerals to integers")
+            (@arg values: [NUMERAL]... "The roman numerals to convert (or each line of stdin)")
+        )
+        (@subcommand validate =>
+            (about: "Checks that roman numerals are valid and canonical, exiting with an error if any are not")
+            (@arg values: [NUMERAL]... "The roman numerals to check (or each line of stdin)")
+        )
+        (@subcommand normalize =>
+            (about: "Converts roman numerals in any accepted form, such as IIII, into their canonical form")
+            (@arg values: [NUMERAL]... "The roman numerals to convert (or each line of stdin)")
+        )
+        (@subcommand table =>
+            (about: "Prints a table of the integers in a range and their roman numerals")
+            (@arg range: <RANGE> {validate_range} "The range of integers (e.g. 1:100 or 1..=100)")
+            (@arg form: --form +takes_value possible_values(&["0", "1", "2", "3", "4"]) "Write the numerals in the given form of the spreadsheet ROMAN function")
+            (@arg lowercase: -l --lowercase "Write the numerals in lower-case")
         )
-        (@arg debug: -d --debug "Debugging output")
-        (@arg bare: -b --bare "Only output the result")
     )
         .name(crate_name!())
         .about(crate_description!())
         .version(crate_version!())
         .author(crate_authors!())
+        .after_help("Every value is converted, carrying on past any that fail, but the exit status is 1 if any of them failed.")
         .get_matches()
 }
 
@@ -89,18 +374,25 @@ fn error_prefix(bare: bool) -> String {
     }
 }
 
-fn print_debug(prefix: String, val: String) {
+/// Writes debugging output, which always goes to stderr so that it is never mixed in with the
+/// results of the structured formats.
+fn print_debug(prefix: &str, val: &str, mut err: impl Write) {
     let dim = Style::new().dimmed();
     let cyan = Cyan.dimmed();
     let prefix = format!("{} = ", prefix);
-    println!("{}{}", dim.paint(prefix), cyan.paint(val.to_string()));
+    writeln!(err, "{}{}", dim.paint(prefix), cyan.paint(val)).unwrap();
 }
 
 #[cfg(test)]
 mod tests {
-    use ansi_term::{Colour::Green, Colour::Red};
+    use ansi_term::{Colour::Cyan, Colour::Green, Colour::Red, Style};
 
-    use crate::{error_prefix, print_integer, print_roman_numeral, result_prefix};
+    use numeris::{roman_table, SpreadsheetForm};
+
+    use crate::{
+        error_prefix, parse_range, print_debug, print_lines, print_row, print_table, print_value,
+        result_prefix, Command, Format, COLUMNS,
+    };
 
     #[test]
     fn result_prefix_is_correct() {
@@ -122,7 +414,7 @@ mod tests {
     fn print_roman_numeral_with_full_output() {
         let mut out = Vec::new();
         let mut err = Vec::new();
-        print_roman_numeral(1, false, &mut out, &mut err);
+        print_value(Command::ToRoman, "1", Format::Text, &mut out, &mut err);
         assert_eq!(err.len(), 0);
         let expected =
             format!("{} {}\n", Green.bold().reverse().paint("RESULT:"), Green.paint("I"));
@@ -133,7 +425,7 @@ mod tests {
     fn print_roman_numeral_with_bare_output() {
         let mut out = Vec::new();
         let mut err = Vec::new();
-        print_roman_numeral(1, true, &mut out, &mut err);
+        print_value(Command::ToRoman, "1", Format::Bare, &mut out, &mut err);
         assert_eq!(err.len(), 0);
         let expected = format!("{}\n", Green.paint("I"));
         assert_eq!(out, expected.as_bytes());
@@ -143,7 +435,7 @@ mod tests {
     fn print_roman_numeral_with_full_error() {
         let mut out = Vec::new();
         let mut err = Vec::new();
-        print_roman_numeral(0, false, &mut out, &mut err);
+        print_value(Command::ToRoman, "0", Format::Text, &mut out, &mut err);
         assert_eq!(out.len(), 0);
         let expected =
             format!("{} {}\n", Red.bold().reverse().paint("ERROR:"), Red.paint("0 is too small"));
@@ -154,7 +446,7 @@ mod tests {
     fn print_roman_numeral_with_bare_error() {
         let mut out = Vec::new();
         let mut err = Vec::new();
-        print_roman_numeral(0, true, &mut out, &mut err);
+        print_value(Command::ToRoman, "0", Format::Bare, &mut out, &mut err);
         assert_eq!(out.len(), 0);
         let expected = format!("{}\n", Red.paint("0 is too small"));
         assert_eq!(err, expected.as_bytes());
@@ -164,7 +456,7 @@ mod tests {
     fn print_integer_with_full_output() {
         let mut out = Vec::new();
         let mut err = Vec::new();
-        print_integer("XI", false, &mut out, &mut err);
+        print_value(Command::ToInt, "XI", Format::Text, &mut out, &mut err);
         assert_eq!(err.len(), 0);
         let expected =
             format!("{} {}\n", Green.bold().reverse().paint("RESULT:"), Green.paint("11"));
@@ -175,7 +467,7 @@ mod tests {
     fn print_integer_with_bare_output() {
         let mut out = Vec::new();
         let mut err = Vec::new();
-        print_integer("XI", true, &mut out, &mut err);
+        print_value(Command::ToInt, "XI", Format::Bare, &mut out, &mut err);
         assert_eq!(err.len(), 0);
         let expected = format!("{}\n", Green.paint("11"));
         assert_eq!(out, expected.as_bytes());
@@ -185,23 +477,269 @@ mod tests {
     fn print_integer_with_full_error() {
         let mut out = Vec::new();
         let mut err = Vec::new();
-        print_integer("Blah", false, &mut out, &mut err);
+        print_value(Command::ToInt, "Blah", Format::Text, &mut out, &mut err);
         assert_eq!(out.len(), 0);
         let expected = format!(
             "{} {}\n",
             Red.bold().reverse().paint("ERROR:"),
-            Red.paint("BLAH is not a valid Roman numeral")
+            Red.paint("Blah is not a valid Roman numeral ('B' at position 1 is not a symbol)")
         );
         assert_eq!(err, expected.as_bytes());
     }
 
+    #[test]
+    fn print_integer_with_specific_errors() {
+        let messages = [
+            (
+                "IIII",
+                "IIII is not a valid Roman numeral (I is repeated 4 times); did you mean IV, III \
+                 or VIII?",
+            ),
+            (
+                "XCX",
+                "XCX is not a valid Roman numeral (X found where at most 9 was expected); did you \
+                 mean C, XCIX or XCV?",
+            ),
+            (
+                "XIVB",
+                "XIVB is not a valid Roman numeral ('B' at position 4 is not a symbol); did you \
+                 mean XIV?",
+            ),
+        ];
+        for (val, msg) in messages.iter() {
+            let mut out = Vec::new();
+            let mut err = Vec::new();
+            print_value(Command::ToInt, val, Format::Bare, &mut out, &mut err);
+            assert_eq!(out.len(), 0);
+            assert_eq!(err, format!("{}\n", Red.paint(*msg)).as_bytes());
+        }
+    }
+
     #[test]
     fn print_integer_with_bare_error() {
         let mut out = Vec::new();
         let mut err = Vec::new();
-        print_integer("Blah", true, &mut out, &mut err);
+        print_value(Command::ToInt, "Blah", Format::Bare, &mut out, &mut err);
         assert_eq!(out.len(), 0);
-        let expected = format!("{}\n", Red.paint("BLAH is not a valid Roman numeral"));
+        let expected = format!(
+            "{}\n",
+            Red.paint("Blah is not a valid Roman numeral ('B' at position 1 is not a symbol)")
+        );
         assert_eq!(err, expected.as_bytes());
     }
+
+    #[test]
+    fn print_lines_of_integers() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        assert!(!print_lines(
+            Command::ToRoman,
+            "1\n\n 4 \nfour\n0\n9\n".as_bytes(),
+            Format::Bare,
+            &mut out,
+            &mut err,
+        ));
+        let expected =
+            format!("{}\n{}\n{}\n", Green.paint("I"), Green.paint("IV"), Green.paint("IX"));
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+        let expected =
+            format!("{}\n{}\n", Red.paint("four is not an integer"), Red.paint("0 is too small"));
+        assert_eq!(String::from_utf8(err).unwrap(), expected);
+    }
+
+    #[test]
+    fn print_lines_of_roman_numerals() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        assert!(!print_lines(
+            Command::ToInt,
+            "XI\r\nBlah\r\nmcm".as_bytes(),
+            Format::Text,
+            &mut out,
+            &mut err,
+        ));
+        let expected = format!(
+            "{} {}\n{} {}\n",
+            Green.bold().reverse().paint("RESULT:"),
+            Green.paint("11"),
+            Green.bold().reverse().paint("RESULT:"),
+            Green.paint("1900")
+        );
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+        let expected = format!(
+            "{} {}\n",
+            Red.bold().reverse().paint("ERROR:"),
+            Red.paint("Blah is not a valid Roman numeral ('B' at position 1 is not a symbol)")
+        );
+        assert_eq!(String::from_utf8(err).unwrap(), expected);
+    }
+
+    #[test]
+    fn detect_kind_of_value() {
+        assert_eq!(Command::of("14"), Command::ToRoman);
+        assert_eq!(Command::of("-1"), Command::ToRoman);
+        assert_eq!(Command::of("XIV"), Command::ToInt);
+        assert_eq!(Command::of("xiv"), Command::ToInt);
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        print_value(Command::Detect, "14", Format::Bare, &mut out, &mut err);
+        print_value(Command::Detect, "XIV", Format::Bare, &mut out, &mut err);
+        print_value(Command::Detect, "1X", Format::Bare, &mut out, &mut err);
+        let expected = format!("{}\n{}\n", Green.paint("XIV"), Green.paint("14"));
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+        assert_eq!(err, format!("{}\n", Red.paint("1X is not an integer")).as_bytes());
+    }
+
+    #[test]
+    fn print_values_as_json() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        print_lines(
+            Command::Detect,
+            "14\nXIV\n1X\nBlah\n".as_bytes(),
+            Format::Json,
+            &mut out,
+            &mut err,
+        );
+        assert_eq!(err.len(), 0);
+        let expected = [
+            r#"{"input":"14","kind":"integer","result":"XIV"}"#,
+            r#"{"input":"XIV","kind":"roman","result":"14"}"#,
+            r#"{"input":"1X","kind":"integer","error":"1X is not an integer"}"#,
+            r#"{"input":"Blah","kind":"roman","error":"Blah is not a valid Roman numeral ('B' at position 1 is not a symbol)"}"#,
+        ];
+        assert_eq!(String::from_utf8(out).unwrap().lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn print_values_as_csv() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        print_row(&COLUMNS, ',', &mut out).unwrap();
+        print_lines(Command::Detect, "14\nIIII\n".as_bytes(), Format::Csv, &mut out, &mut err);
+        assert_eq!(err.len(), 0);
+        let expected = [
+            "input,kind,result,error",
+            "14,integer,XIV,",
+            "IIII,roman,,\"IIII is not a valid Roman numeral (I is repeated 4 times); did you mean \
+             IV, III or VIII?\"",
+        ];
+        assert_eq!(String::from_utf8(out).unwrap().lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn print_values_as_tsv() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        print_lines(Command::ToInt, "XIV\nX2\n".as_bytes(), Format::Tsv, &mut out, &mut err);
+        print_row(&["X\tI", "roman"], '\t', &mut out).unwrap();
+        assert_eq!(err.len(), 0);
+        let expected = [
+            "XIV\troman\t14\t",
+            "X2\troman\t\tX2 is not a valid Roman numeral ('2' at position 2 is not a symbol); did \
+             you mean X, XI or XV?",
+            "X I\troman",
+        ];
+        assert_eq!(String::from_utf8(out).unwrap().lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn parse_ranges() {
+        assert_eq!(parse_range("1:100"), Ok((1, 100)));
+        assert_eq!(parse_range("1..=100"), Ok((1, 100)));
+        assert_eq!(parse_range(" 1 .. 100"), Ok((1, 99)));
+        assert_eq!(
+            parse_range("1-100"),
+            Err(String::from("1-100 is not a range (e.g. 1:100 or 1..=100)"))
+        );
+        assert_eq!(parse_range("1:X"), Err(String::from("X is not an integer")));
+        assert_eq!(parse_range("5..5"), Err(String::from("5..5 is empty")));
+        assert_eq!(
+            parse_range("0:10"),
+            Err(String::from("0 is too small to be written as a Roman numeral"))
+        );
+        assert_eq!(
+            parse_range("1:4000"),
+            Err(String::from("4000 is too large to be written as a Roman numeral"))
+        );
+    }
+
+    #[test]
+    fn print_tables() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let table = roman_table(8..=10).unwrap().lowercase();
+        print_table(table, Format::Text, &mut out, &mut err);
+        let table = roman_table(49..=50).unwrap().form(SpreadsheetForm::Simplified);
+        print_table(table, Format::Csv, &mut out, &mut err);
+        assert_eq!(err.len(), 0);
+        let expected = format!(
+            " 8  {}\n 9  {}\n10  {}\n49,integer,IL,\n50,integer,L,\n",
+            Green.paint("viii"),
+            Green.paint("ix"),
+            Green.paint("x")
+        );
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn validate_roman_numerals() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        assert!(print_lines(
+            Command::Validate,
+            "XIV\n".as_bytes(),
+            Format::Bare,
+            &mut out,
+            &mut err
+        ));
+        assert!(!print_lines(
+            Command::Validate,
+            "XIV\nIIII\n".as_bytes(),
+            Format::Bare,
+            &mut out,
+            &mut err
+        ));
+        let expected =
+            format!("{}\n{}\n", Green.paint("XIV is valid"), Green.paint("XIV is valid"));
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+        let expected = Red.paint(
+            "IIII is not a valid Roman numeral (I is repeated 4 times); did you mean IV, III or \
+             VIII?",
+        );
+        assert_eq!(String::from_utf8(err).unwrap(), format!("{}\n", expected));
+    }
+
+    #[test]
+    fn normalize_roman_numerals() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        assert!(print_value(Command::Normalize, "IIII", Format::Bare, &mut out, &mut err));
+        assert!(print_value(
+            Command::Normalize,
+            "mdcccclxxxxviiii",
+            Format::Bare,
+            &mut out,
+            &mut err
+        ));
+        assert!(!print_value(Command::Normalize, "MMMM", Format::Bare, &mut out, &mut err));
+        let expected = format!("{}\n{}\n", Green.paint("IV"), Green.paint("MCMXCIX"));
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+        assert_eq!(
+            String::from_utf8(err).unwrap(),
+            format!("{}\n", Red.paint("4000 is too large; did you mean MMCM, MMM or MMMI?"))
+        );
+    }
+
+    #[test]
+    fn print_debug_output() {
+        let mut err = Vec::new();
+        print_debug("range", "1..=3", &mut err);
+        let expected = format!(
+            "{}{}\n",
+            Style::new().dimmed().paint("range = "),
+            Cyan.dimmed().paint("1..=3")
+        );
+        assert_eq!(String::from_utf8(err).unwrap(), expected);
+    }
 }
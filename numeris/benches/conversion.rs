@@ -0,0 +1,79 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use numeris::{
+    integer_to_roman, integer_to_roman_unchecked, roman_to_integer, roman_to_integer_lenient,
+    roman_to_integer_trusted, MAX_VALUE, MIN_VALUE,
+};
+
+fn all_numerals() -> Vec<String> {
+    (MIN_VALUE..=MAX_VALUE).map(|val| integer_to_roman(val).unwrap()).collect()
+}
+
+fn bench_integer_to_roman(c: &mut Criterion) {
+    c.bench_function("integer_to_roman (all values)", |b| {
+        b.iter(|| {
+            for val in MIN_VALUE..=MAX_VALUE {
+                black_box(integer_to_roman(black_box(val)).unwrap());
+            }
+        })
+    });
+    c.bench_function("integer_to_roman_unchecked (all values)", |b| {
+        b.iter(|| {
+            for val in MIN_VALUE..=MAX_VALUE {
+                black_box(integer_to_roman_unchecked(black_box(val)));
+            }
+        })
+    });
+}
+
+fn bench_roman_to_integer(c: &mut Criterion) {
+    let numerals = all_numerals();
+    let lower_case: Vec<String> = numerals.iter().map(|rn| rn.to_lowercase()).collect();
+    c.bench_function("roman_to_integer (all values)", |b| {
+        b.iter(|| {
+            for numeral in &numerals {
+                black_box(roman_to_integer(black_box(numeral)).unwrap());
+            }
+        })
+    });
+    c.bench_function("roman_to_integer (all values, lower-case)", |b| {
+        b.iter(|| {
+            for numeral in &lower_case {
+                black_box(roman_to_integer(black_box(numeral)).unwrap());
+            }
+        })
+    });
+    c.bench_function("roman_to_integer_trusted (all values)", |b| {
+        b.iter(|| {
+            for numeral in &numerals {
+                black_box(roman_to_integer_trusted(black_box(numeral)));
+            }
+        })
+    });
+    c.bench_function("roman_to_integer (invalid)", |b| {
+        b.iter(|| {
+            for numeral in ["MMMM", "CMCM", "XIIII", "MCMXCIX!"].iter() {
+                black_box(roman_to_integer(black_box(numeral)).unwrap_err());
+            }
+        })
+    });
+}
+
+fn bench_roman_to_integer_lenient(c: &mut Criterion) {
+    let numerals = all_numerals();
+    c.bench_function("roman_to_integer_lenient (all values)", |b| {
+        b.iter(|| {
+            for numeral in &numerals {
+                black_box(roman_to_integer_lenient(black_box(numeral)).unwrap());
+            }
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_integer_to_roman,
+    bench_roman_to_integer,
+    bench_roman_to_integer_lenient
+);
+criterion_main!(benches);
@@ -0,0 +1,97 @@
+#ifndef NUMERIS_H
+#define NUMERIS_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * The conversion succeeded.
+ */
+#define NUMERIS_OK 0
+
+/**
+ * The value is too large to be written as a Roman numeral.
+ */
+#define NUMERIS_VALUE_TOO_LARGE 1
+
+/**
+ * The value is too small to be written as a Roman numeral.
+ */
+#define NUMERIS_VALUE_TOO_SMALL 2
+
+/**
+ * The numeral could not be parsed.
+ */
+#define NUMERIS_UNPARSABLE 3
+
+/**
+ * The numeral contains a character that is not a Roman numeral symbol.
+ */
+#define NUMERIS_INVALID_CHARACTER 4
+
+/**
+ * The numeral repeats a symbol too many times in a row.
+ */
+#define NUMERIS_INVALID_REPETITION 5
+
+/**
+ * The numeral contains a symbol that is too large for its position.
+ */
+#define NUMERIS_OUT_OF_ORDER 6
+
+/**
+ * The numeral is empty, or contains only whitespace.
+ */
+#define NUMERIS_EMPTY_STRING 7
+
+/**
+ * A required pointer argument was null.
+ */
+#define NUMERIS_NULL_POINTER -1
+
+/**
+ * The output buffer is too small to hold the result.
+ */
+#define NUMERIS_BUFFER_TOO_SMALL -2
+
+/**
+ * The size of a buffer that can hold any numeral, including its terminating NUL. The longest
+ * numeral is `MMMDCCCLXXXVIII`.
+ */
+#define NUMERIS_BUFFER_LEN 16
+
+/**
+ * Converts an integer into a Roman numeral, as by `integer_to_roman`.
+ *
+ * The numeral is written to `buf`, which must be able to hold `buf_len` bytes, followed by a
+ * terminating NUL. A buffer of `NUMERIS_BUFFER_LEN` bytes is always large enough. Nothing is
+ * written to `buf` unless the conversion succeeds.
+ *
+ * # Safety
+ *
+ * `buf` must be null, or valid for writes of `buf_len` bytes.
+ */
+int32_t numeris_itor(uint32_t val, char *buf, size_t buf_len);
+
+/**
+ * Converts a NUL-terminated string representing a Roman numeral into an integer, as by
+ * `roman_to_integer`.
+ *
+ * The value is written to `out`, which is left unchanged unless the conversion succeeds.
+ *
+ * # Safety
+ *
+ * `numeral` must be null, or point to a NUL-terminated string. `out` must be null, or valid
+ * for writes of a `u32`.
+ */
+int32_t numeris_rtoi(const char *numeral, uint32_t *out);
+
+/**
+ * A short, static description of a status code, as a NUL-terminated string. The string must
+ * not be freed.
+ */
+const char *numeris_status_message(int32_t status);
+
+#endif  /* NUMERIS_H */
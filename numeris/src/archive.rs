@@ -0,0 +1,134 @@
+//! Archiving [`RomanNumeral`]s with `rkyv`. Requires the `rkyv` feature.
+//!
+//! A `RomanNumeral` implements the `rkyv` `Archive`, `Serialize` and `Deserialize` traits, and is
+//! archived as an [`ArchivedRomanNumeral`] holding its value, so that archives of numerals can be
+//! read in place.
+
+use rkyv::bytecheck::{CheckBytes, Verify};
+use rkyv::munge::munge;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::{Archive, Archived, Deserialize, Place, Portable, Serialize};
+
+use super::RomanNumeral;
+
+/// A [`RomanNumeral`], archived by `rkyv` as its value.
+///
+/// An `ArchivedRomanNumeral` is four bytes, holding the value of the numeral as a `u32`, so it
+/// can be read in place from a memory-mapped archive. When an archive is validated, as by
+/// `rkyv::access`, each archived numeral is checked to be in range. Requires the `rkyv` feature.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{ArchivedRomanNumeral, RomanNumeral};
+/// use rkyv::rancor::Error;
+///
+/// let numerals = vec![RomanNumeral::new(42).unwrap(), RomanNumeral::new(1142).unwrap()];
+/// let bytes = rkyv::to_bytes::<Error>(&numerals).unwrap();
+/// let archived = rkyv::access::<rkyv::Archived<Vec<RomanNumeral>>, Error>(&bytes).unwrap();
+/// assert_eq!(archived[1].value(), 1142);
+/// assert_eq!(archived[1].to_numeral().to_string(), "MCXLII");
+/// let deserialized = rkyv::deserialize::<Vec<RomanNumeral>, Error>(archived).unwrap();
+/// assert_eq!(deserialized, numerals);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[rkyv(crate = rkyv)]
+#[repr(transparent)]
+pub struct ArchivedRomanNumeral(Archived<u32>);
+
+impl ArchivedRomanNumeral {
+    /// The value of the numeral.
+    pub fn value(&self) -> u32 {
+        self.0.to_native()
+    }
+
+    /// Copies the numeral out of the archive.
+    ///
+    /// # Panics
+    ///
+    /// If the archived value is out of range. This can only happen if the archive was accessed
+    /// without being validated, and was not written by `rkyv`.
+    pub fn to_numeral(&self) -> RomanNumeral {
+        RomanNumeral::new(self.value()).expect("archived numerals are always in range")
+    }
+}
+
+impl PartialEq<RomanNumeral> for ArchivedRomanNumeral {
+    fn eq(&self, other: &RomanNumeral) -> bool {
+        self.value() == other.value()
+    }
+}
+
+impl Archive for RomanNumeral {
+    type Archived = ArchivedRomanNumeral;
+    type Resolver = ();
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedRomanNumeral(value) = out);
+        self.value().resolve(resolver, value);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for RomanNumeral {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D> Deserialize<RomanNumeral, D> for ArchivedRomanNumeral
+where
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<RomanNumeral, D::Error> {
+        RomanNumeral::new(self.value()).map_err(Source::new)
+    }
+}
+
+// SAFETY: `verify` only checks that the value is in range; any `u32` is a valid `Archived<u32>`.
+unsafe impl<C> Verify<C> for ArchivedRomanNumeral
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        RomanNumeral::new(self.value()).map(drop).map_err(Source::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::rancor::Error;
+
+    use crate::{ArchivedRomanNumeral, RomanNumeral, MAX_VALUE, MIN_VALUE};
+
+    #[test]
+    fn round_trip_all_numerals() {
+        let numerals: Vec<RomanNumeral> =
+            (MIN_VALUE..=MAX_VALUE).map(|val| RomanNumeral::new(val).unwrap()).collect();
+        let bytes = rkyv::to_bytes::<Error>(&numerals).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Vec<RomanNumeral>>, Error>(&bytes).unwrap();
+        assert!(archived.iter().zip(&numerals).all(|(archived, rn)| archived == rn));
+        assert_eq!(rkyv::deserialize::<Vec<RomanNumeral>, Error>(archived).unwrap(), numerals);
+    }
+
+    #[test]
+    fn store_raw_values() {
+        let bytes = rkyv::to_bytes::<Error>(&RomanNumeral::new(1142).unwrap()).unwrap();
+        assert_eq!(bytes.as_slice(), 1142u32.to_le_bytes());
+        let archived = rkyv::access::<ArchivedRomanNumeral, Error>(&bytes).unwrap();
+        assert_eq!((archived.value(), archived.to_numeral().to_string()), (1142, "MCXLII".into()));
+    }
+
+    #[test]
+    fn reject_values_out_of_range() {
+        for val in [0u32, 4000].iter() {
+            let bytes = rkyv::to_bytes::<Error>(val).unwrap();
+            let error = rkyv::access::<ArchivedRomanNumeral, Error>(&bytes).unwrap_err();
+            assert!(error.to_string().contains("to be written as a Roman numeral"), "{}", error);
+            let error = rkyv::from_bytes::<RomanNumeral, Error>(&bytes).unwrap_err();
+            assert!(error.to_string().contains("to be written as a Roman numeral"), "{}", error);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Parsing [`RomanNumeral`] command-line arguments with `clap`. Requires the `clap` feature.
+//!
+//! [`RomanNumeralParser`] validates arguments such as `--chapter <ROMAN>`, and reports invalid
+//! numerals in the same way as any other invalid argument.
+
+use std::ffi::OsStr;
+
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+
+use super::{roman_to_integer_with_suggestions, RomanNumeral};
+
+/// Parses command-line arguments as [`RomanNumeral`]s, for use with `clap`.
+///
+/// Arguments are parsed as by [`roman_to_integer`][crate::roman_to_integer]. Invalid arguments
+/// are reported with the reason that they are invalid and the canonical numerals that were most
+/// likely meant, as by [`roman_to_integer_with_suggestions`]. This is the
+/// default parser for [`RomanNumeral`] arguments, so it is used automatically by
+/// `value_parser!(RomanNumeral)` and by the derive API. Requires the `clap` feature.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{value_parser, Arg, Command};
+/// use numeris::RomanNumeral;
+///
+/// let cmd = Command::new("book")
+///     .arg(Arg::new("chapter").long("chapter").value_parser(value_parser!(RomanNumeral)));
+/// let matches = cmd.clone().try_get_matches_from(["book", "--chapter", "XIV"]).unwrap();
+/// assert_eq!(matches.get_one::<RomanNumeral>("chapter").unwrap().value(), 14);
+///
+/// let error = cmd.try_get_matches_from(["book", "--chapter", "XIIII"]).unwrap_err();
+/// assert!(error.to_string().contains("I is repeated 4 times in a row"));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RomanNumeralParser;
+
+impl TypedValueParser for RomanNumeralParser {
+    type Value = RomanNumeral;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let value =
+            value.to_str().ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        let val = roman_to_integer_with_suggestions(value).map_err(|e| {
+            let arg = arg.map_or_else(|| String::from("..."), |arg| arg.to_string());
+            let message = format!("invalid value '{}' for '{}': {}\n", value, arg, e);
+            Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+        })?;
+        Ok(RomanNumeral::new(val).expect("a parsed numeral is in range"))
+    }
+}
+
+impl ValueParserFactory for RomanNumeral {
+    type Parser = RomanNumeralParser;
+
+    fn value_parser() -> Self::Parser {
+        RomanNumeralParser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::error::ErrorKind;
+    use clap::{value_parser, Arg, Command};
+
+    use crate::{RomanNumeral, RomanNumeralParser};
+
+    fn command() -> Command {
+        Command::new("test")
+            .arg(Arg::new("chapter").long("chapter").value_parser(RomanNumeralParser))
+            .arg(Arg::new("volume").value_parser(value_parser!(RomanNumeral)))
+    }
+
+    #[test]
+    fn parse_numeral_arguments() {
+        let matches = command().try_get_matches_from(["test", "--chapter", "xlii", "IV"]).unwrap();
+        assert_eq!(matches.get_one::<RomanNumeral>("chapter").unwrap().value(), 42);
+        assert_eq!(matches.get_one::<RomanNumeral>("volume").unwrap().value(), 4);
+    }
+
+    #[test]
+    fn report_invalid_arguments() {
+        let error = command().try_get_matches_from(["test", "--chapter", "XIIX"]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::ValueValidation);
+        assert_eq!(
+            error.to_string(),
+            "error: invalid value 'XIIX' for '--chapter <chapter>': IX found where at most 1 was \
+             expected; did you mean XVIII, XIX or XIII?\n"
+        );
+        let error = command().try_get_matches_from(["test", "14"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "error: invalid value '14' for '[volume]': '1' at position 1 is not a Roman numeral \
+             symbol; did you mean I, II or IV?\n"
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! Attic Greek numerals, also known as acrophonic or Herodianic numerals. Requires the `attic`
+//! feature.
+//!
+//! Attic numerals are written additively, like Roman numerals without subtraction, using the
+//! initial letters of the Greek number words: `Ι` for 1, `Π` (_pente_) for 5, `Δ` (_deka_) for
+//! 10, `Η` (_hekaton_) for 100, `Χ` (_chilioi_) for 1000 and `Μ` (_myrioi_) for 10,000. The
+//! multiples of five above 5 are written as a `Π` enclosing the symbol that it multiplies, e.g.
+//! `𐅄` (U+10144) for 50. Each of `Ι`, `Δ`, `Η`, `Χ` and `Μ` may be repeated up to four times in a
+//! row, so `ΧΧ𐅅ΗΗ𐅄ΔΙΙΙ` is 2763.
+//!
+//! # Examples
+//!
+//! ```
+//! use numeris::attic::{attic_to_integer, integer_to_attic};
+//!
+//! assert_eq!(integer_to_attic(9).unwrap(), "ΠΙΙΙΙ");
+//! assert_eq!(integer_to_attic(2763).unwrap(), "ΧΧ𐅅ΗΗ𐅄ΔΙΙΙ");
+//! assert_eq!(attic_to_integer("ΔΔΠΙ").unwrap(), 26);
+//! ```
+
+use super::{NumeralSystem, Position, Result, RomanNumeralError};
+
+/// The minimum value supported for Attic numerals
+pub const MIN_VALUE: u32 = 1;
+/// The maximum value supported for Attic numerals
+pub const MAX_VALUE: u32 = 99_999;
+
+/// The symbols of Attic numerals, from largest to smallest, with their values and the largest
+/// number of times that each may appear in a row.
+const SYMBOLS: [(&str, u32, usize); 10] = [
+    ("𐅇", 50_000, 1),
+    ("Μ", 10_000, 4),
+    ("𐅆", 5000, 1),
+    ("Χ", 1000, 4),
+    ("𐅅", 500, 1),
+    ("Η", 100, 4),
+    ("𐅄", 50, 1),
+    ("Δ", 10, 4),
+    ("Π", 5, 1),
+    ("Ι", 1, 4),
+];
+
+/// Characters that are accepted in place of the symbols when parsing: the acrophonic five
+/// (U+10143) and the gamma, which is often used for the older form of pi, for 5.
+const SYMBOL_ALTERNATIVES: [(char, char); 2] = [('\u{10143}', 'Π'), ('Γ', 'Π')];
+
+/// Attic Greek numerals, written by [`integer_to_attic`] and parsed by [`attic_to_integer`], as a
+/// [`NumeralSystem`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Attic;
+
+impl NumeralSystem for Attic {
+    fn id(&self) -> &'static str {
+        "attic"
+    }
+
+    fn name(&self) -> &'static str {
+        "Attic Greek"
+    }
+
+    fn min_value(&self) -> u32 {
+        MIN_VALUE
+    }
+
+    fn max_value(&self) -> u32 {
+        MAX_VALUE
+    }
+
+    fn format(&self, val: u32) -> Result<String> {
+        integer_to_attic(val)
+    }
+
+    fn parse(&self, numeral: &str) -> Result<u32> {
+        attic_to_integer(numeral)
+    }
+}
+
+/// Converts an integer into a string representing an Attic Greek numeral.
+///
+/// The numeral is written with the capital Greek letters, and with the Greek acrophonic symbols
+/// (U+10144 to U+10147) for 50, 500, 5000 and 50,000.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::attic::integer_to_attic;
+///
+/// assert_eq!(integer_to_attic(4).unwrap(), "ΙΙΙΙ");
+/// assert_eq!(integer_to_attic(1142).unwrap(), "ΧΗΔΔΔΔΙΙ");
+/// assert_eq!(integer_to_attic(55_555).unwrap(), "𐅇𐅆𐅅𐅄Π");
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralErrorKind` | Reason |
+/// | ----------------------- | ------ |
+/// | [`ValueTooSmall`][a] | `val` is 0 |
+/// | [`ValueTooLarge`][b] | `val` is greater than [`MAX_VALUE`] |
+///
+/// [a]: crate::RomanNumeralError::ValueTooSmall
+/// [b]: crate::RomanNumeralError::ValueTooLarge
+pub fn integer_to_attic(val: u32) -> Result<String> {
+    if val < MIN_VALUE {
+        return Err(RomanNumeralError::ValueTooSmall(val));
+    } else if val > MAX_VALUE {
+        return Err(RomanNumeralError::ValueTooLarge(val));
+    }
+    let mut numeral = String::new();
+    let mut remaining = val;
+    for &(symbol, value, _) in SYMBOLS.iter() {
+        numeral.push_str(&symbol.repeat((remaining / value) as usize));
+        remaining %= value;
+    }
+    Ok(numeral)
+}
+
+/// Converts a string representing an Attic Greek numeral into an integer.
+///
+/// The symbols must be written from largest to smallest, and may not be repeated more than
+/// four times in a row (or, for the multiples of five, more than once). Case is ignored, and
+/// leading and trailing whitespace is trimmed. The acrophonic five (`𐅃`, U+10143) and the gamma
+/// (`Γ`) are accepted for 5, as they are found in inscriptions in place of `Π`.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::attic::attic_to_integer;
+///
+/// assert_eq!(attic_to_integer("ΧΗΔΔΔΔΙΙ").unwrap(), 1142);
+/// assert_eq!(attic_to_integer(" ΓΙΙ ").unwrap(), 7);
+/// assert_eq!(attic_to_integer("𐅄δ").unwrap(), 60);
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralError` | Reason |
+/// | ----------------------- | ------ |
+/// | [`InvalidCharacter`][a] | `numeral` contains a character that is not an Attic numeral symbol |
+/// | [`InvalidRepetition`][b] | `numeral` repeats a symbol too many times in a row |
+/// | [`OutOfOrder`][c] | `numeral` contains a symbol that is too large for its position |
+/// | [`EmptyString`][d] |  `numeral` is an empty string or contains only whitespace |
+///
+/// [a]: crate::RomanNumeralError::InvalidCharacter
+/// [b]: crate::RomanNumeralError::InvalidRepetition
+/// [c]: crate::RomanNumeralError::OutOfOrder
+/// [d]: crate::RomanNumeralError::EmptyString
+pub fn attic_to_integer(numeral: &str) -> Result<u32> {
+    let trimmed = numeral.trim();
+    if trimmed.is_empty() {
+        return Err(RomanNumeralError::EmptyString);
+    }
+    let start = Position::default().after(&numeral[..numeral.len() - numeral.trim_start().len()]);
+    let symbols = trimmed
+        .char_indices()
+        .map(|(byte, ch)| {
+            let pos = start.after(&trimmed[..byte]);
+            symbol(ch).ok_or(RomanNumeralError::InvalidCharacter { ch, pos }).map(|i| (i, pos))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let mut total = 0;
+    let mut previous: Option<(usize, usize)> = None;
+    for (n, &(index, pos)) in symbols.iter().enumerate() {
+        let (found, value, max_repetitions) = SYMBOLS[index];
+        let run = match previous {
+            Some((previous, run)) if previous == index => run + 1,
+            Some((previous, run)) if previous > index => {
+                let expected_max = expected_after(previous, run);
+                return Err(RomanNumeralError::OutOfOrder { found, expected_max, pos });
+            }
+            _ => 1,
+        };
+        if run > max_repetitions {
+            let count = run - 1 + symbols[n..].iter().take_while(|&&(i, _)| i == index).count();
+            return Err(RomanNumeralError::InvalidRepetition { symbol: found, count, pos });
+        }
+        total += value;
+        previous = Some((index, run));
+    }
+    Ok(total)
+}
+
+/// The largest value that may follow `run` repetitions of the symbol at `index`, which is 0 if
+/// nothing may follow them.
+fn expected_after(index: usize, run: usize) -> u32 {
+    let (_, value, max_repetitions) = SYMBOLS[index];
+    if run < max_repetitions {
+        value
+    } else {
+        SYMBOLS.get(index + 1).map_or(0, |&(_, value, _)| value)
+    }
+}
+
+/// The index in [`SYMBOLS`] of the symbol for `ch`, in either case.
+fn symbol(ch: char) -> Option<usize> {
+    let ch = ch.to_uppercase().next().unwrap_or(ch);
+    let ch = SYMBOL_ALTERNATIVES
+        .iter()
+        .find(|&&(alternative, _)| alternative == ch)
+        .map_or(ch, |&(_, symbol)| symbol);
+    SYMBOLS.iter().position(|(symbol, _, _)| symbol.starts_with(ch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attic_to_integer, integer_to_attic, MAX_VALUE, MIN_VALUE};
+    use crate::{Position, RomanNumeralError};
+
+    #[test]
+    fn convert_integers_to_attic() {
+        assert_eq!(integer_to_attic(1).unwrap(), "Ι");
+        assert_eq!(integer_to_attic(5).unwrap(), "Π");
+        assert_eq!(integer_to_attic(49).unwrap(), "ΔΔΔΔΠΙΙΙΙ");
+        assert_eq!(integer_to_attic(500).unwrap(), "𐅅");
+        assert_eq!(integer_to_attic(10_000).unwrap(), "Μ");
+        assert_eq!(integer_to_attic(99_999).unwrap(), "𐅇ΜΜΜΜ𐅆ΧΧΧΧ𐅅ΗΗΗΗ𐅄ΔΔΔΔΠΙΙΙΙ");
+    }
+
+    #[test]
+    fn reject_values_out_of_range() {
+        assert_eq!(integer_to_attic(0), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(integer_to_attic(100_000), Err(RomanNumeralError::ValueTooLarge(100_000)));
+    }
+
+    #[test]
+    fn round_trip_all_values() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let numeral = integer_to_attic(val).unwrap();
+            assert_eq!(attic_to_integer(&numeral), Ok(val), "{}", numeral);
+            assert_eq!(attic_to_integer(&numeral.to_lowercase()), Ok(val), "{}", numeral);
+        }
+    }
+
+    #[test]
+    fn accept_alternative_symbols() {
+        assert_eq!(attic_to_integer(" ΔΠ "), Ok(15));
+        assert_eq!(attic_to_integer("Δ𐅃"), Ok(15));
+        assert_eq!(attic_to_integer("ΔΓ"), Ok(15));
+        assert_eq!(attic_to_integer("δπιι"), Ok(17));
+    }
+
+    #[test]
+    fn reject_invalid_numerals() {
+        assert_eq!(attic_to_integer(" "), Err(RomanNumeralError::EmptyString));
+        assert_eq!(
+            attic_to_integer("ΙΙΙΙΙΙ"),
+            Err(RomanNumeralError::InvalidRepetition {
+                symbol: "Ι",
+                count: 6,
+                pos: Position::new(8, 4)
+            })
+        );
+        assert_eq!(
+            attic_to_integer("ΠΠ"),
+            Err(RomanNumeralError::InvalidRepetition {
+                symbol: "Π",
+                count: 2,
+                pos: Position::new(2, 1)
+            })
+        );
+        assert_eq!(
+            attic_to_integer("ΙΔ"),
+            Err(RomanNumeralError::OutOfOrder {
+                found: "Δ",
+                expected_max: 1,
+                pos: Position::new(2, 1)
+            })
+        );
+        assert_eq!(
+            attic_to_integer("ΙΙΙΙ𐅄"),
+            Err(RomanNumeralError::OutOfOrder {
+                found: "𐅄",
+                expected_max: 0,
+                pos: Position::new(8, 4)
+            })
+        );
+        assert_eq!(
+            attic_to_integer("ΧX"),
+            Err(RomanNumeralError::InvalidCharacter { ch: 'X', pos: Position::new(2, 1) })
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Caching of recent conversions. Requires the `cache` feature.
+//!
+//! [`RomanCache`] remembers recent conversions in both directions, for services that convert the
+//! same values again and again.
+
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use lru::LruCache;
+
+use super::{integer_to_roman, roman_to_integer, Result};
+
+/// A thread-safe cache of recent conversions, in both directions.
+///
+/// Each direction holds at most `capacity` results, discarding the least recently used result
+/// when it is full. Numerals are returned as shared `Arc<str>`s, so converting the same value
+/// repeatedly does not allocate. Errors are never cached. Requires the `cache` feature.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use std::sync::Arc;
+///
+/// use numeris::RomanCache;
+///
+/// let cache = RomanCache::new(NonZeroUsize::new(100).unwrap());
+/// let first = cache.integer_to_roman(1142).unwrap();
+/// let second = cache.integer_to_roman(1142).unwrap();
+/// assert_eq!(&*first, "MCXLII");
+/// assert!(Arc::ptr_eq(&first, &second));
+/// assert_eq!(cache.roman_to_integer("MCXLII").unwrap(), 1142);
+/// ```
+#[derive(Debug)]
+pub struct RomanCache {
+    numerals: Mutex<LruCache<u32, Arc<str>>>,
+    values: Mutex<LruCache<String, u32>>,
+}
+
+impl RomanCache {
+    /// Creates an empty cache which holds up to `capacity` results in each direction.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        RomanCache {
+            numerals: Mutex::new(LruCache::new(capacity)),
+            values: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Converts an integer into a Roman numeral, reusing a cached result if there is one.
+    ///
+    /// See [`integer_to_roman`] for details and possible errors.
+    pub fn integer_to_roman(&self, val: u32) -> Result<Arc<str>> {
+        if let Some(numeral) = lock(&self.numerals).get(&val) {
+            return Ok(Arc::clone(numeral));
+        }
+        let numeral: Arc<str> = Arc::from(integer_to_roman(val)?);
+        lock(&self.numerals).put(val, Arc::clone(&numeral));
+        Ok(numeral)
+    }
+
+    /// Converts a Roman numeral into an integer, reusing a cached result if there is one.
+    ///
+    /// Results are cached by the exact input, so e.g. `XIV` and `xiv` are cached separately. See
+    /// [`roman_to_integer`] for details and possible errors.
+    pub fn roman_to_integer(&self, numeral: &str) -> Result<u32> {
+        if let Some(&val) = lock(&self.values).get(numeral) {
+            return Ok(val);
+        }
+        let val = roman_to_integer(numeral)?;
+        lock(&self.values).put(numeral.to_string(), val);
+        Ok(val)
+    }
+
+    /// Discards all of the cached results.
+    pub fn clear(&self) {
+        lock(&self.numerals).clear();
+        lock(&self.values).clear();
+    }
+}
+
+/// Locks one side of the cache. A panic while the lock was held cannot leave the cache in an
+/// inconsistent state, so poisoning is ignored.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use std::sync::Arc;
+    use std::thread;
+
+    use crate::{RomanCache, RomanNumeralError};
+
+    fn cache(capacity: usize) -> RomanCache {
+        RomanCache::new(NonZeroUsize::new(capacity).unwrap())
+    }
+
+    #[test]
+    fn reuse_cached_numerals() {
+        let cache = cache(2);
+        let first = cache.integer_to_roman(14).unwrap();
+        assert_eq!(&*first, "XIV");
+        assert!(Arc::ptr_eq(&first, &cache.integer_to_roman(14).unwrap()));
+        cache.integer_to_roman(15).unwrap();
+        cache.integer_to_roman(16).unwrap();
+        assert!(!Arc::ptr_eq(&first, &cache.integer_to_roman(14).unwrap()));
+    }
+
+    #[test]
+    fn convert_cached_values() {
+        let cache = cache(10);
+        for _ in 0..2 {
+            assert_eq!(cache.roman_to_integer("XIV"), Ok(14));
+            assert_eq!(cache.roman_to_integer(" xiv "), Ok(14));
+        }
+        cache.clear();
+        assert_eq!(cache.roman_to_integer("MMXX"), Ok(2020));
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let cache = cache(10);
+        for _ in 0..2 {
+            assert_eq!(cache.integer_to_roman(0), Err(RomanNumeralError::ValueTooSmall(0)));
+            assert_eq!(cache.roman_to_integer(""), Err(RomanNumeralError::EmptyString));
+        }
+    }
+
+    #[test]
+    fn share_cache_between_threads() {
+        let cache = Arc::new(cache(100));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for val in 1..=200 {
+                        let numeral = cache.integer_to_roman(val).unwrap();
+                        assert_eq!(cache.roman_to_integer(&numeral), Ok(val));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+}
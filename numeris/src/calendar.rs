@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::{integer_to_roman, roman_to_integer, Result, RomanNumeralError};
+
+/// The traditional year of the founding of Rome, in years BCE. This is the year 1 AUC.
+const FOUNDING_BCE: u32 = 753;
+/// The abbreviation written after a year _ab urbe condita_.
+const AUC: &str = "AUC";
+/// Alternative abbreviations accepted when parsing.
+const AUC_ALTERNATIVES: [&str; 2] = [AUC, "A.U.C."];
+
+/// A year of the proleptic Julian calendar, counted either before or in the Common Era.
+///
+/// There is no year 0: 1 BCE is immediately followed by 1 CE. Years are ordered
+/// chronologically, so every year BCE is earlier than every year CE.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Year {
+    /// A year before the Common Era, equivalent to BC.
+    Bce(u32),
+    /// A year of the Common Era, equivalent to AD.
+    Ce(u32),
+}
+
+impl Year {
+    /// Counts this year _ab urbe condita_ (AUC), i.e. from the traditional founding of Rome in
+    /// 753 BCE, which is 1 AUC.
+    ///
+    /// ```
+    /// use numeris::Year;
+    ///
+    /// assert_eq!(Year::Bce(753).to_auc().unwrap(), 1);
+    /// assert_eq!(Year::Bce(1).to_auc().unwrap(), 753);
+    /// assert_eq!(Year::Ce(1).to_auc().unwrap(), 754);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// | `RomanNumeralErrorKind` | Reason |
+    /// | ----------------------- | ------ |
+    /// | [`ValueTooSmall`][a] | the year is 0, or is before the founding of Rome |
+    /// | [`ValueTooLarge`][b] | the year is too large to be counted in a `u32` |
+    ///
+    /// Either error holds the year that was given, e.g. `ValueTooSmall(800)` for 800 BCE.
+    ///
+    /// [a]: crate::RomanNumeralError::ValueTooSmall
+    /// [b]: crate::RomanNumeralError::ValueTooLarge
+    pub fn to_auc(self) -> Result<u32> {
+        match self {
+            Year::Bce(0) | Year::Ce(0) => Err(RomanNumeralError::ValueTooSmall(0)),
+            Year::Bce(year) if year > FOUNDING_BCE => Err(RomanNumeralError::ValueTooSmall(year)),
+            Year::Bce(year) => Ok(FOUNDING_BCE + 1 - year),
+            Year::Ce(year) => {
+                year.checked_add(FOUNDING_BCE).ok_or(RomanNumeralError::ValueTooLarge(year))
+            }
+        }
+    }
+
+    /// Finds the year that is counted as `auc` _ab urbe condita_.
+    ///
+    /// ```
+    /// use numeris::Year;
+    ///
+    /// assert_eq!(Year::from_auc(710).unwrap(), Year::Bce(44));
+    /// assert_eq!(Year::from_auc(2777).unwrap(), Year::Ce(2024));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// | `RomanNumeralErrorKind` | Reason |
+    /// | ----------------------- | ------ |
+    /// | [`ValueTooSmall`][a] | `auc` is 0 |
+    ///
+    /// [a]: crate::RomanNumeralError::ValueTooSmall
+    pub fn from_auc(auc: u32) -> Result<Year> {
+        match auc {
+            0 => Err(RomanNumeralError::ValueTooSmall(0)),
+            _ if auc <= FOUNDING_BCE => Ok(Year::Bce(FOUNDING_BCE + 1 - auc)),
+            _ => Ok(Year::Ce(auc - FOUNDING_BCE)),
+        }
+    }
+
+    /// The year as an astronomical year number, in which 1 BCE is year 0, 2 BCE is year -1, and
+    /// so on.
+    fn astronomical(self) -> i64 {
+        match self {
+            Year::Bce(year) => 1 - i64::from(year),
+            Year::Ce(year) => i64::from(year),
+        }
+    }
+}
+
+impl fmt::Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Year::Bce(year) => write!(f, "{} BCE", year),
+            Year::Ce(year) => write!(f, "{} CE", year),
+        }
+    }
+}
+
+impl PartialOrd for Year {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Year {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.astronomical().cmp(&other.astronomical())
+    }
+}
+
+/// Converts a [`Year`] into a year _ab urbe condita_, written as a Roman numeral followed by
+/// `AUC`.
+///
+/// Years are counted from the traditional founding of Rome in 753 BCE, which is 1 AUC, as by
+/// [`Year::to_auc`]. As there is no year 0, 1 BCE is 753 AUC and 1 CE is 754 AUC.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{year_to_auc, Year};
+///
+/// assert_eq!(year_to_auc(Year::Ce(2024)).unwrap(), "MMDCCLXXVII AUC");
+/// assert_eq!(year_to_auc(Year::Bce(44)).unwrap(), "DCCX AUC");
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`Year::to_auc`], or by [`integer_to_roman`] for years after 3246 CE,
+/// which is 3999 AUC.
+pub fn year_to_auc(year: Year) -> Result<String> {
+    Ok(format!("{} {}", integer_to_roman(year.to_auc()?)?, AUC))
+}
+
+/// Converts a year _ab urbe condita_, written as a Roman numeral, into a [`Year`].
+///
+/// The numeral may be followed by `AUC` or `A.U.C.`, separated from it by whitespace. The numeral
+/// is parsed as by [`roman_to_integer`], and the year is found as by [`Year::from_auc`]. Case is
+/// ignored, and leading and trailing whitespace is trimmed.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{auc_to_year, Year};
+///
+/// assert_eq!(auc_to_year("MMDCCLXXVII AUC").unwrap(), Year::Ce(2024));
+/// assert_eq!(auc_to_year("dccx a.u.c.").unwrap(), Year::Bce(44));
+/// assert_eq!(auc_to_year("I").unwrap(), Year::Bce(753));
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer`] for the numeral.
+pub fn auc_to_year(numeral: &str) -> Result<Year> {
+    let trimmed = numeral.trim_end();
+    let without_suffix = AUC_ALTERNATIVES
+        .iter()
+        .filter(|suffix| trimmed.len() > suffix.len())
+        .find_map(|suffix| {
+            let (rest, end) = trimmed.split_at(trimmed.len() - suffix.len());
+            let is_separate = rest.ends_with(char::is_whitespace);
+            (end.eq_ignore_ascii_case(suffix) && is_separate).then_some(rest)
+        })
+        .unwrap_or(trimmed);
+    let auc = roman_to_integer(without_suffix)?;
+    Year::from_auc(auc)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{auc_to_year, year_to_auc, RomanNumeralError, Year, MAX_VALUE, MIN_VALUE};
+
+    #[test]
+    fn count_from_the_founding_of_rome() {
+        assert_eq!(Year::Bce(753).to_auc(), Ok(1));
+        assert_eq!(Year::Bce(44).to_auc(), Ok(710));
+        assert_eq!(Year::Bce(1).to_auc(), Ok(753));
+        assert_eq!(Year::Ce(1).to_auc(), Ok(754));
+        assert_eq!(Year::Ce(2024).to_auc(), Ok(2777));
+        assert_eq!(Year::from_auc(753), Ok(Year::Bce(1)));
+        assert_eq!(Year::from_auc(754), Ok(Year::Ce(1)));
+    }
+
+    #[test]
+    fn reject_years_that_cannot_be_counted() {
+        assert_eq!(Year::Ce(0).to_auc(), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(Year::Bce(0).to_auc(), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(Year::Bce(754).to_auc(), Err(RomanNumeralError::ValueTooSmall(754)));
+        assert_eq!(Year::Bce(800).to_auc(), Err(RomanNumeralError::ValueTooSmall(800)));
+        assert_eq!(Year::Bce(u32::MAX).to_auc(), Err(RomanNumeralError::ValueTooSmall(u32::MAX)));
+        let too_large = u32::MAX - 752;
+        assert_eq!(Year::Ce(too_large).to_auc(), Err(RomanNumeralError::ValueTooLarge(too_large)));
+        assert_eq!(Year::Ce(too_large - 1).to_auc(), Ok(u32::MAX));
+        assert_eq!(Year::from_auc(0), Err(RomanNumeralError::ValueTooSmall(0)));
+    }
+
+    #[test]
+    fn write_years_as_numerals() {
+        assert_eq!(year_to_auc(Year::Ce(2024)).unwrap(), "MMDCCLXXVII AUC");
+        assert_eq!(year_to_auc(Year::Bce(753)).unwrap(), "I AUC");
+        assert_eq!(year_to_auc(Year::Ce(3246)).unwrap(), "MMMCMXCIX AUC");
+        assert_eq!(year_to_auc(Year::Ce(3247)), Err(RomanNumeralError::ValueTooLarge(4000)));
+    }
+
+    #[test]
+    fn read_years_from_numerals() {
+        assert_eq!(auc_to_year(" MMDCCLXXVII  AUC "), Ok(Year::Ce(2024)));
+        assert_eq!(auc_to_year("mmdcclxxvii auc"), Ok(Year::Ce(2024)));
+        assert_eq!(auc_to_year("DCCX A.U.C."), Ok(Year::Bce(44)));
+        assert_eq!(auc_to_year("DCCLIII"), Ok(Year::Bce(1)));
+        assert!(matches!(
+            auc_to_year("AUC"),
+            Err(RomanNumeralError::InvalidCharacter { ch: 'A', .. })
+        ));
+        assert!(auc_to_year("DCCXAUC").is_err());
+        assert_eq!(auc_to_year(" AUC"), Err(RomanNumeralError::EmptyString));
+    }
+
+    #[test]
+    fn round_trip_all_years() {
+        for auc in MIN_VALUE..=MAX_VALUE {
+            let year = Year::from_auc(auc).unwrap();
+            assert_eq!(year.to_auc(), Ok(auc));
+            assert_eq!(auc_to_year(&year_to_auc(year).unwrap()), Ok(year));
+        }
+    }
+
+    #[test]
+    fn order_years_chronologically() {
+        let mut years = vec![Year::Ce(1), Year::Bce(1), Year::Ce(2024), Year::Bce(753)];
+        years.sort();
+        assert_eq!(years, [Year::Bce(753), Year::Bce(1), Year::Ce(1), Year::Ce(2024)]);
+        assert_eq!(Year::Bce(44).to_string(), "44 BCE");
+    }
+}
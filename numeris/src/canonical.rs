@@ -0,0 +1,82 @@
+use super::{integer_to_roman, roman_to_integer, roman_to_integer_lenient, Result};
+
+/// Converts a Roman numeral in any accepted form into its canonical form.
+///
+/// The input is parsed as by [`roman_to_integer_lenient`], so non-canonical forms such as `IIII`
+/// or `IIX` are accepted, and then converted back into a Roman numeral as by
+/// [`integer_to_roman`]. The result is always a canonical, upper-case Roman numeral.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::normalize;
+///
+/// assert_eq!(normalize("IIII").unwrap(), "IV");
+/// assert_eq!(normalize(" mdcccclxxxxviiii ").unwrap(), "MCMXCIX");
+/// assert_eq!(normalize("XIV").unwrap(), "XIV");
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer_lenient`].
+pub fn normalize(numeral: &str) -> Result<String> {
+    integer_to_roman(roman_to_integer_lenient(numeral)?)
+}
+
+/// Checks whether a string is a Roman numeral in its canonical form.
+///
+/// This is `true` exactly when [`roman_to_integer`] would accept `numeral`. Case is ignored, and
+/// leading and trailing whitespace is trimmed, so `" xiv "` is considered canonical. Numerals
+/// that are only accepted by [`roman_to_integer_lenient`], such as `XIIII`, are not canonical.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::is_canonical;
+///
+/// assert!(is_canonical("XIV"));
+/// assert!(!is_canonical("XIIII"));
+/// assert!(!is_canonical("BAD"));
+/// ```
+pub fn is_canonical(numeral: &str) -> bool {
+    roman_to_integer(numeral).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{is_canonical, normalize, Position, RomanNumeralError};
+
+    #[test]
+    fn normalize_variants() {
+        assert_eq!(normalize("IIII").unwrap(), String::from("IV"));
+        assert_eq!(normalize("VIIII").unwrap(), String::from("IX"));
+        assert_eq!(normalize("XIIX").unwrap(), String::from("XVIII"));
+        assert_eq!(normalize("IC").unwrap(), String::from("XCIX"));
+        assert_eq!(normalize("cccc").unwrap(), String::from("CD"));
+    }
+
+    #[test]
+    fn normalize_canonical_values() {
+        for val in ["I", "XLII", "MCMLXXXIV", "MMMCMXCIX"].iter() {
+            assert_eq!(normalize(val).unwrap(), String::from(*val));
+        }
+    }
+
+    #[test]
+    fn check_canonical_values() {
+        for val in ["I", "iv", " XLII ", "MCMLXXXIV", "MMMCMXCIX"].iter() {
+            assert!(is_canonical(val), "{}", val);
+        }
+        for val in ["", "IIII", "XIIII", "VIIII", "IIX", "IC", "CMD", "MMMM", "X1"].iter() {
+            assert!(!is_canonical(val), "{}", val);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_values() {
+        assert_eq!(normalize(""), Err(RomanNumeralError::EmptyString));
+        assert_eq!(normalize("MMMM"), Err(RomanNumeralError::ValueTooLarge(4000)));
+        let pos = Position::new(0, 0);
+        assert_eq!(normalize("BAD"), Err(RomanNumeralError::InvalidCharacter { ch: 'B', pos }));
+    }
+}
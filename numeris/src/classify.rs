@@ -0,0 +1,124 @@
+use super::{integer_to_roman, roman_to_integer_lenient};
+
+/// The conventions that a Roman numeral can follow, as determined by [`classify`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NumeralForm {
+    /// The canonical form, using subtractive pairs for 4s and 9s (e.g. `XIV`, `XC`).
+    Canonical,
+    /// The canonical form, except that a final 4 is written as `IIII`, as on clock faces
+    /// (e.g. `XIIII`).
+    ClockVariant,
+    /// A form where some 4s and 9s are written additively (e.g. `VIIII`, `MDCCCCX`).
+    AdditiveVariant,
+    /// Any other form accepted by [`roman_to_integer_lenient`][crate::roman_to_integer_lenient],
+    /// such as the irregular subtractive `IIX` (8) or `IC` (99).
+    Medieval,
+    /// Not a Roman numeral at all.
+    Invalid,
+}
+
+/// Determines which convention a Roman numeral follows.
+///
+/// Case is ignored, and leading and trailing whitespace is trimmed. Numerals which follow more
+/// than one convention are reported as the most specific one: a numeral that is canonical is
+/// never reported as any kind of variant, and a clock-face variant is never reported as an
+/// additive variant.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{classify, NumeralForm};
+///
+/// assert_eq!(classify("XIV"), NumeralForm::Canonical);
+/// assert_eq!(classify("XIIII"), NumeralForm::ClockVariant);
+/// assert_eq!(classify("XVIIII"), NumeralForm::AdditiveVariant);
+/// assert_eq!(classify("XIIX"), NumeralForm::Medieval);
+/// assert_eq!(classify("XYZ"), NumeralForm::Invalid);
+/// ```
+pub fn classify(numeral: &str) -> NumeralForm {
+    let value = match roman_to_integer_lenient(numeral) {
+        Ok(value) => value,
+        Err(_) => return NumeralForm::Invalid,
+    };
+    let numeral = numeral.trim().to_ascii_uppercase();
+    let canonical = integer_to_roman(value).unwrap_or_default();
+    if numeral == canonical {
+        NumeralForm::Canonical
+    } else if value % 10 == 4 && numeral == format!("{}IIII", &canonical[..canonical.len() - 2]) {
+        NumeralForm::ClockVariant
+    } else if matches_places(&numeral, &decimal_places(value)) {
+        NumeralForm::AdditiveVariant
+    } else {
+        NumeralForm::Medieval
+    }
+}
+
+/// For each decimal place of `value`, the accepted ways of writing its digit: the canonical form,
+/// followed by the additive form for 4s and 9s.
+fn decimal_places(value: u32) -> Vec<Vec<String>> {
+    const SYMBOLS: [(u32, &str, &str, &str); 4] =
+        [(1000, "M", "", ""), (100, "C", "D", "M"), (10, "X", "L", "C"), (1, "I", "V", "X")];
+    SYMBOLS
+        .iter()
+        .map(|&(place, one, five, ten)| {
+            let digit = (value / place % 10) as usize;
+            match digit {
+                4 => vec![format!("{}{}", one, five), one.repeat(4)],
+                9 => vec![format!("{}{}", one, ten), format!("{}{}", five, one.repeat(4))],
+                5..=8 => vec![format!("{}{}", five, one.repeat(digit - 5))],
+                _ => vec![one.repeat(digit)],
+            }
+        })
+        .collect()
+}
+
+fn matches_places(numeral: &str, places: &[Vec<String>]) -> bool {
+    match places.split_first() {
+        None => numeral.is_empty(),
+        Some((forms, rest)) => forms
+            .iter()
+            .filter_map(|form| numeral.strip_prefix(form.as_str()))
+            .any(|remaining| matches_places(remaining, rest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{classify, NumeralForm};
+
+    #[test]
+    fn classify_canonical_values() {
+        for val in ["I", "IV", "xiv", " MCMXCIX ", "MMMCMXCIX"].iter() {
+            assert_eq!(classify(val), NumeralForm::Canonical, "{}", val);
+        }
+    }
+
+    #[test]
+    fn classify_clock_variants() {
+        for val in ["IIII", "XIIII", "MCMXCIIII"].iter() {
+            assert_eq!(classify(val), NumeralForm::ClockVariant, "{}", val);
+        }
+    }
+
+    #[test]
+    fn classify_additive_variants() {
+        let values = ["VIIII", "XXXX", "MDCCCCX", "MCMXCVIIII", "CCCCXLIV", "mdcccclxxxxviiii"];
+        for val in values.iter() {
+            assert_eq!(classify(val), NumeralForm::AdditiveVariant, "{}", val);
+        }
+    }
+
+    #[test]
+    fn classify_medieval_values() {
+        for val in ["IIX", "XIIX", "IC", "VV", "XM", "IIIIII"].iter() {
+            assert_eq!(classify(val), NumeralForm::Medieval, "{}", val);
+        }
+    }
+
+    #[test]
+    fn classify_invalid_values() {
+        for val in ["", "  ", "ABC", "X1", "MMMM", "IIIIIIIIIIX"].iter() {
+            assert_eq!(classify(val), NumeralForm::Invalid, "{}", val);
+        }
+    }
+}
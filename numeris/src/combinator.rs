@@ -0,0 +1,91 @@
+//! Parsing Roman numerals with `nom`. Requires the `nom` feature.
+//!
+//! [`roman_numeral`] parses a numeral as part of a larger grammar built with `nom` parser
+//! combinators, such as a numbered heading or a citation.
+
+use nom::error::{ErrorKind, ParseError};
+use nom::{Err, IResult};
+
+use super::rtoi::parse_prefix;
+
+/// A `nom` parser for a Roman numeral at the start of the input.
+///
+/// This consumes the longest valid Roman numeral at the start of the input, in either case, and
+/// returns its value along with the rest of the input. Unlike [`roman_to_integer`][a], whitespace
+/// is not trimmed, and it is not an error for the numeral to be followed by anything else, even
+/// by more symbols: given `XIIII`, the numeral `XIII` is consumed, and `I` is left over. If the
+/// input does not start with a numeral, the parser fails with [`ErrorKind::OneOf`]. Requires the
+/// `nom` feature.
+///
+/// # Examples
+///
+/// ```
+/// use nom::bytes::complete::tag;
+/// use nom::sequence::preceded;
+/// use nom::IResult;
+/// use numeris::roman_numeral;
+///
+/// fn chapter(input: &str) -> IResult<&str, u32> {
+///     preceded(tag("Chapter "), roman_numeral)(input)
+/// }
+///
+/// assert_eq!(roman_numeral::<()>("XLII: the answer"), Ok((": the answer", 42)));
+/// assert_eq!(chapter("Chapter xiv."), Ok((".", 14)));
+/// assert!(chapter("Chapter 14.").is_err());
+/// ```
+///
+/// [a]: crate::roman_to_integer
+pub fn roman_numeral<'a, E>(input: &'a str) -> IResult<&'a str, u32, E>
+where
+    E: ParseError<&'a str>,
+{
+    match parse_prefix(input) {
+        (_, 0) => Err(Err::Error(E::from_error_kind(input, ErrorKind::OneOf))),
+        (value, len) => Ok((&input[len..], value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::{Error, ErrorKind};
+    use nom::multi::separated_list1;
+    use nom::{character::complete::char, Err, IResult};
+
+    use crate::{integer_to_roman, roman_numeral, MAX_VALUE, MIN_VALUE};
+
+    fn parse(input: &str) -> IResult<&str, u32> {
+        roman_numeral(input)
+    }
+
+    #[test]
+    fn parse_whole_numerals() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let numeral = integer_to_roman(val).unwrap();
+            assert_eq!(parse(&numeral), Ok(("", val)));
+            assert_eq!(parse(&numeral.to_lowercase()), Ok(("", val)));
+        }
+    }
+
+    #[test]
+    fn parse_longest_valid_prefix() {
+        assert_eq!(parse("XIV rest"), Ok((" rest", 14)));
+        assert_eq!(parse("XIIII"), Ok(("I", 13)));
+        assert_eq!(parse("MMMM"), Ok(("M", 3000)));
+        assert_eq!(parse("IIV"), Ok(("IV", 1)));
+        assert_eq!(parse("CMXCIXABC"), Ok(("ABC", 999)));
+        assert_eq!(parse("vé"), Ok(("é", 5)));
+    }
+
+    #[test]
+    fn fail_without_numeral() {
+        for val in ["", " XIV", "ABC", "14"].iter() {
+            assert_eq!(parse(val), Err(Err::Error(Error::new(*val, ErrorKind::OneOf))));
+        }
+    }
+
+    #[test]
+    fn combine_with_other_parsers() {
+        let mut list = separated_list1(char::<&str, Error<&str>>(','), roman_numeral);
+        assert_eq!(list("I,IV,XLII;"), Ok((";", vec![1, 4, 42])));
+    }
+}
@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+
+use super::{roman_to_integer, Result};
+
+/// Compares two Roman numerals by value.
+///
+/// Both numerals are parsed as by [`roman_to_integer`], so case and surrounding whitespace are
+/// ignored.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use numeris::compare_numerals;
+///
+/// assert_eq!(compare_numerals("XIV", "IX").unwrap(), Ordering::Greater);
+/// assert_eq!(compare_numerals("xiv", " XIV ").unwrap(), Ordering::Equal);
+/// assert!(compare_numerals("XIV", "BAD").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer`] for either numeral. If both are invalid, the error
+/// for `a` is returned.
+pub fn compare_numerals(a: &str, b: &str) -> Result<Ordering> {
+    let a = roman_to_integer(a)?;
+    let b = roman_to_integer(b)?;
+    Ok(a.cmp(&b))
+}
+
+/// Sorts a slice of Roman numerals by value, in ascending order.
+///
+/// Numerals are parsed as by [`roman_to_integer`]. Entries that are not valid numerals are placed
+/// after all of the valid ones, in the order in which they originally appeared. The sort is
+/// stable, so numerals with the same value (e.g. `IV` and `iv`) also keep their original order.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::sort_by_roman_value;
+///
+/// let mut numerals = vec!["X", "unknown", "IX", "MCM", "C", "?"];
+/// sort_by_roman_value(&mut numerals);
+/// assert_eq!(numerals, ["IX", "X", "C", "MCM", "unknown", "?"]);
+/// ```
+pub fn sort_by_roman_value<S: AsRef<str>>(numerals: &mut [S]) {
+    numerals.sort_by_cached_key(|numeral| match roman_to_integer(numeral.as_ref()) {
+        Ok(value) => (false, value),
+        Err(_) => (true, 0),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::{compare_numerals, sort_by_roman_value, Position, RomanNumeralError};
+
+    #[test]
+    fn compare_by_value() {
+        assert_eq!(compare_numerals("IX", "X"), Ok(Ordering::Less));
+        assert_eq!(compare_numerals("MCM", "MC"), Ok(Ordering::Greater));
+        assert_eq!(compare_numerals("iv", "IV"), Ok(Ordering::Equal));
+        // Lexical order would put "IX" after "C"
+        assert_eq!(compare_numerals("IX", "C"), Ok(Ordering::Less));
+    }
+
+    #[test]
+    fn surface_parse_errors() {
+        let pos = Position::new(0, 0);
+        assert_eq!(
+            compare_numerals("A", "B"),
+            Err(RomanNumeralError::InvalidCharacter { ch: 'A', pos })
+        );
+        assert_eq!(compare_numerals("X", ""), Err(RomanNumeralError::EmptyString));
+    }
+
+    #[test]
+    fn sort_strings_by_value() {
+        let mut numerals: Vec<String> =
+            ["MMXX", "iv", "III", "XL", "IV", "V"].iter().map(|s| s.to_string()).collect();
+        sort_by_roman_value(&mut numerals);
+        assert_eq!(numerals, ["III", "iv", "IV", "V", "XL", "MMXX"]);
+    }
+
+    #[test]
+    fn sort_invalid_entries_last() {
+        let mut numerals = ["", "IIII", "II", "Louis", "I"];
+        sort_by_roman_value(&mut numerals);
+        assert_eq!(numerals, ["I", "II", "", "IIII", "Louis"]);
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+use super::integer_to_roman;
+use super::numeral::pad_numeral;
+
+/// Displays an integer as an upper-case Roman numeral, e.g. `XLII`.
+///
+/// This is a lightweight alternative to [`RomanNumeral`][crate::RomanNumeral] for one-off
+/// formatting. Like [`RomanNumeral`][crate::RomanNumeral], it honours the width, fill and
+/// alignment of the format. Values that cannot be written as Roman numerals are displayed as
+/// ordinary integers instead, as CSS does for the `upper-roman` list style.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::UpperRoman;
+///
+/// assert_eq!(format!("Chapter {}", UpperRoman(14)), "Chapter XIV");
+/// assert_eq!(format!("[{:>5}]", UpperRoman(4)), "[   IV]");
+/// assert_eq!(UpperRoman(0).to_string(), "0");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UpperRoman(pub u32);
+
+/// Displays an integer as a lower-case Roman numeral, e.g. `xlii`.
+///
+/// This behaves exactly like [`UpperRoman`], except for the case of the symbols.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::LowerRoman;
+///
+/// assert_eq!(format!("{}", LowerRoman(4)), "iv");
+/// assert_eq!(format!("({:<4})", LowerRoman(2)), "(ii  )");
+/// assert_eq!(LowerRoman(4000).to_string(), "4000");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LowerRoman(pub u32);
+
+impl fmt::Display for UpperRoman {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match integer_to_roman(self.0) {
+            Ok(numeral) => pad_numeral(f, &numeral),
+            Err(_) => fmt::Display::fmt(&self.0, f),
+        }
+    }
+}
+
+impl fmt::Display for LowerRoman {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match integer_to_roman(self.0) {
+            Ok(numeral) => pad_numeral(f, &numeral.to_ascii_lowercase()),
+            Err(_) => fmt::Display::fmt(&self.0, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{integer_to_roman, LowerRoman, UpperRoman, MAX_VALUE, MIN_VALUE};
+
+    #[test]
+    fn display_numerals_in_either_case() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let numeral = integer_to_roman(val).unwrap();
+            assert_eq!(UpperRoman(val).to_string(), numeral);
+            assert_eq!(LowerRoman(val).to_string(), numeral.to_ascii_lowercase());
+        }
+    }
+
+    #[test]
+    fn pad_displayed_numerals() {
+        assert_eq!(format!("{:6}|{:<6}|", UpperRoman(42), LowerRoman(42)), "  XLII|xlii  |");
+        assert_eq!(format!("{:.^7}", LowerRoman(9)), "..ix...");
+    }
+
+    #[test]
+    fn display_values_out_of_range_as_integers() {
+        assert_eq!(UpperRoman(0).to_string(), "0");
+        assert_eq!(LowerRoman(4000).to_string(), "4000");
+        assert_eq!(format!("{:>6}", UpperRoman(u32::MAX)), "4294967295");
+        assert_eq!(format!("{:06}", LowerRoman(4000)), "004000");
+    }
+}
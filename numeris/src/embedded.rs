@@ -0,0 +1,75 @@
+//! `defmt` logging for embedded firmware. Requires the `defmt` feature.
+//!
+//! [`RomanNumeral`], [`RomanNumeralError`] and [`Position`] implement `defmt::Format`, so that
+//! they can be logged over RTT with `defmt::info!` and the like, without formatting them on the
+//! device.
+
+use defmt::{Format, Formatter};
+
+use super::itor::atoms_of;
+use super::{Position, RomanNumeral, RomanNumeralError};
+
+/// Logs the numeral itself, e.g. `MCXLII`, one symbol at a time, without allocating.
+impl Format for RomanNumeral {
+    fn format(&self, f: Formatter<'_>) {
+        for atom in atoms_of(self.value()) {
+            defmt::write!(f, "{=str}", atom.symbol());
+        }
+    }
+}
+
+/// Logs the same message as the error's `Display` implementation.
+impl Format for RomanNumeralError {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            RomanNumeralError::ValueTooLarge(val) => {
+                defmt::write!(f, "{=u32} is too large to be written as a Roman numeral", val)
+            }
+            RomanNumeralError::ValueTooSmall(val) => {
+                defmt::write!(f, "{=u32} is too small to be written as a Roman numeral", val)
+            }
+            RomanNumeralError::Unparsable(val) => {
+                defmt::write!(f, "{=str} could not be parsed", val.as_str())
+            }
+            RomanNumeralError::InvalidCharacter { ch, pos } => defmt::write!(
+                f,
+                "'{=char}' at position {=usize} is not a Roman numeral symbol",
+                ch,
+                pos.char + 1
+            ),
+            RomanNumeralError::InvalidRepetition { symbol, count, .. } => {
+                defmt::write!(f, "{=str} is repeated {=usize} times in a row", symbol, count)
+            }
+            RomanNumeralError::OutOfOrder { found, expected_max, .. } => defmt::write!(
+                f,
+                "{=str} found where at most {=u32} was expected",
+                found,
+                expected_max
+            ),
+            RomanNumeralError::EmptyString => defmt::write!(f, "no Roman numeral provided"),
+        }
+    }
+}
+
+impl Format for Position {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "Position {{ byte: {=usize}, char: {=usize} }}", self.byte, self.char)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use defmt::Format;
+
+    use crate::{Position, RomanNumeral, RomanNumeralError};
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn implement_format() {
+        assert_format::<RomanNumeral>();
+        assert_format::<RomanNumeralError>();
+        assert_format::<Position>();
+        assert_format::<Option<RomanNumeral>>();
+    }
+}
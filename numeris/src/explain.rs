@@ -0,0 +1,160 @@
+use std::convert::TryInto;
+use std::fmt;
+
+use itertools::Itertools;
+
+use super::rtoi::tokenize_at;
+use super::{integer_to_roman, Position, Result};
+
+/// One step in the breakdown of a Roman numeral, as returned by [`explain`] and [`explain_roman`].
+///
+/// Each step is a run of identical symbols (e.g. `II`) or a subtractive pair (e.g. `XL`), along
+/// with its value and the total of all of the steps up to and including it.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ConversionStep {
+    symbols: String,
+    value: u32,
+    running_total: u32,
+}
+
+impl ConversionStep {
+    /// The symbols that make up this step, e.g. `XL` or `II`.
+    pub fn symbols(&self) -> &str {
+        &self.symbols
+    }
+
+    /// The value of the symbols in this step.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// The total value of the numeral up to and including this step.
+    pub fn running_total(&self) -> u32 {
+        self.running_total
+    }
+}
+
+impl fmt::Display for ConversionStep {
+    /// Formats the step as `symbols=value`, e.g. `XL=40`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.symbols, self.value)
+    }
+}
+
+/// Breaks down the conversion of an integer into a Roman numeral, step by step.
+///
+/// The integer is converted as by [`integer_to_roman`], and the result is explained as by
+/// [`explain_roman`].
+///
+/// # Examples
+///
+/// ```
+/// use numeris::explain;
+///
+/// let steps = explain(1142).unwrap();
+/// let steps: Vec<String> = steps.iter().map(|step| step.to_string()).collect();
+/// assert_eq!(steps, ["M=1000", "C=100", "XL=40", "II=2"]);
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`integer_to_roman`].
+pub fn explain<T>(val: T) -> Result<Vec<ConversionStep>>
+where
+    T: TryInto<u32> + PartialOrd + Default,
+{
+    explain_roman(&integer_to_roman(val)?)
+}
+
+/// Breaks down the conversion of a Roman numeral into an integer, step by step.
+///
+/// The numeral must be valid, as accepted by [`roman_to_integer`][crate::roman_to_integer]. Each
+/// step covers a run of identical symbols or a subtractive pair, in the order in which they appear
+/// in the numeral; the running total of the last step is the value of the whole numeral.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::explain_roman;
+///
+/// let steps = explain_roman("mcxlii").unwrap();
+/// assert_eq!(steps[2].symbols(), "XL");
+/// assert_eq!(steps[2].value(), 40);
+/// assert_eq!(steps[2].running_total(), 1140);
+/// assert_eq!(steps.last().unwrap().running_total(), 1142);
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer`][crate::roman_to_integer].
+pub fn explain_roman(numeral: &str) -> Result<Vec<ConversionStep>> {
+    let atoms = tokenize_at(numeral, Position::default())?;
+    let mut running_total = 0;
+    let steps = atoms
+        .into_iter()
+        .group_by(|rn| rn.symbol)
+        .into_iter()
+        .map(|(symbol, group)| {
+            let group = group.collect_vec();
+            let value = group.iter().map(|rn| rn.value).sum();
+            running_total += value;
+            ConversionStep { symbols: symbol.repeat(group.len()), value, running_total }
+        })
+        .collect();
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{explain, explain_roman, RomanNumeralError};
+
+    fn describe(val: u32) -> Vec<(String, u32, u32)> {
+        let steps = explain(val).unwrap();
+        steps.iter().map(|s| (s.symbols().to_string(), s.value(), s.running_total())).collect()
+    }
+
+    #[test]
+    fn explain_integers() {
+        assert_eq!(describe(1), [(String::from("I"), 1, 1)]);
+        assert_eq!(
+            describe(1142),
+            [
+                (String::from("M"), 1000, 1000),
+                (String::from("C"), 100, 1100),
+                (String::from("XL"), 40, 1140),
+                (String::from("II"), 2, 1142),
+            ]
+        );
+        assert_eq!(
+            describe(3999),
+            [
+                (String::from("MMM"), 3000, 3000),
+                (String::from("CM"), 900, 3900),
+                (String::from("XC"), 90, 3990),
+                (String::from("IX"), 9, 3999),
+            ]
+        );
+    }
+
+    #[test]
+    fn explain_roman_numerals() {
+        let steps = explain_roman(" xxxviii ").unwrap();
+        let steps: Vec<String> = steps.iter().map(|step| step.to_string()).collect();
+        assert_eq!(steps, ["XXX=30", "V=5", "III=3"]);
+    }
+
+    #[test]
+    fn running_total_matches_value() {
+        for val in [4, 49, 444, 1994, 2468].iter() {
+            let steps = explain(*val).unwrap();
+            assert_eq!(steps.last().unwrap().running_total(), *val);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_input() {
+        assert_eq!(explain(0), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(explain_roman(""), Err(RomanNumeralError::EmptyString));
+        assert!(explain_roman("IIII").is_err());
+    }
+}
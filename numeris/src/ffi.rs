@@ -0,0 +1,196 @@
+//! C bindings for the conversion functions. Requires the `ffi` feature.
+//!
+//! The C declarations for this module are in `include/numeris.h`, which is generated by
+//! `cbindgen`. Each function returns one of the `NUMERIS_*` status codes, which is
+//! [`NUMERIS_OK`] on success. Positive codes correspond to the kinds of
+//! [`RomanNumeralError`], and negative codes report misuse of the bindings themselves, such as a
+//! null pointer.
+//!
+//! ```c
+//! #include "numeris.h"
+//!
+//! char numeral[NUMERIS_BUFFER_LEN];
+//! uint32_t value;
+//! if (numeris_itor(1142, numeral, sizeof numeral) == NUMERIS_OK) {
+//!     puts(numeral); // MCXLII
+//! }
+//! int status = numeris_rtoi("XIIII", &value);
+//! if (status != NUMERIS_OK) {
+//!     puts(numeris_status_message(status)); // a symbol is repeated too many times
+//! }
+//! ```
+//!
+//! The crate is only built as a Rust library, so the shared or static library for C is built by
+//! passing the crate type to `cargo rustc`, e.g.
+//! `cargo rustc -p numeris --release --features ffi --crate-type cdylib`.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use super::{integer_to_roman, roman_to_integer_bytes, RomanNumeralError};
+
+// The doc comments on the items below are copied into `include/numeris.h` by `cbindgen`, so they
+// are written as plain text, without intra-doc links.
+
+/// The conversion succeeded.
+pub const NUMERIS_OK: i32 = 0;
+/// The value is too large to be written as a Roman numeral.
+pub const NUMERIS_VALUE_TOO_LARGE: i32 = 1;
+/// The value is too small to be written as a Roman numeral.
+pub const NUMERIS_VALUE_TOO_SMALL: i32 = 2;
+/// The numeral could not be parsed.
+pub const NUMERIS_UNPARSABLE: i32 = 3;
+/// The numeral contains a character that is not a Roman numeral symbol.
+pub const NUMERIS_INVALID_CHARACTER: i32 = 4;
+/// The numeral repeats a symbol too many times in a row.
+pub const NUMERIS_INVALID_REPETITION: i32 = 5;
+/// The numeral contains a symbol that is too large for its position.
+pub const NUMERIS_OUT_OF_ORDER: i32 = 6;
+/// The numeral is empty, or contains only whitespace.
+pub const NUMERIS_EMPTY_STRING: i32 = 7;
+/// A required pointer argument was null.
+pub const NUMERIS_NULL_POINTER: i32 = -1;
+/// The output buffer is too small to hold the result.
+pub const NUMERIS_BUFFER_TOO_SMALL: i32 = -2;
+
+/// The size of a buffer that can hold any numeral, including its terminating NUL. The longest
+/// numeral is `MMMDCCCLXXXVIII`.
+pub const NUMERIS_BUFFER_LEN: usize = 16;
+
+fn status_of(error: &RomanNumeralError) -> i32 {
+    match error {
+        RomanNumeralError::ValueTooLarge(_) => NUMERIS_VALUE_TOO_LARGE,
+        RomanNumeralError::ValueTooSmall(_) => NUMERIS_VALUE_TOO_SMALL,
+        RomanNumeralError::Unparsable(_) => NUMERIS_UNPARSABLE,
+        RomanNumeralError::InvalidCharacter { .. } => NUMERIS_INVALID_CHARACTER,
+        RomanNumeralError::InvalidRepetition { .. } => NUMERIS_INVALID_REPETITION,
+        RomanNumeralError::OutOfOrder { .. } => NUMERIS_OUT_OF_ORDER,
+        RomanNumeralError::EmptyString => NUMERIS_EMPTY_STRING,
+    }
+}
+
+/// Converts an integer into a Roman numeral, as by `integer_to_roman`.
+///
+/// The numeral is written to `buf`, which must be able to hold `buf_len` bytes, followed by a
+/// terminating NUL. A buffer of `NUMERIS_BUFFER_LEN` bytes is always large enough. Nothing is
+/// written to `buf` unless the conversion succeeds.
+///
+/// # Safety
+///
+/// `buf` must be null, or valid for writes of `buf_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn numeris_itor(val: u32, buf: *mut c_char, buf_len: usize) -> i32 {
+    if buf.is_null() {
+        return NUMERIS_NULL_POINTER;
+    }
+    let numeral = match integer_to_roman(val) {
+        Ok(numeral) => numeral,
+        Err(e) => return status_of(&e),
+    };
+    if numeral.len() >= buf_len {
+        return NUMERIS_BUFFER_TOO_SMALL;
+    }
+    std::ptr::copy_nonoverlapping(numeral.as_ptr(), buf.cast::<u8>(), numeral.len());
+    *buf.add(numeral.len()) = 0;
+    NUMERIS_OK
+}
+
+/// Converts a NUL-terminated string representing a Roman numeral into an integer, as by
+/// `roman_to_integer`.
+///
+/// The value is written to `out`, which is left unchanged unless the conversion succeeds.
+///
+/// # Safety
+///
+/// `numeral` must be null, or point to a NUL-terminated string. `out` must be null, or valid
+/// for writes of a `u32`.
+#[no_mangle]
+pub unsafe extern "C" fn numeris_rtoi(numeral: *const c_char, out: *mut u32) -> i32 {
+    if numeral.is_null() || out.is_null() {
+        return NUMERIS_NULL_POINTER;
+    }
+    match roman_to_integer_bytes(CStr::from_ptr(numeral).to_bytes()) {
+        Ok(val) => {
+            *out = val;
+            NUMERIS_OK
+        }
+        Err(e) => status_of(&e),
+    }
+}
+
+/// A short, static description of a status code, as a NUL-terminated string. The string must
+/// not be freed.
+#[no_mangle]
+pub extern "C" fn numeris_status_message(status: i32) -> *const c_char {
+    let message: &'static [u8] = match status {
+        NUMERIS_OK => b"success\0",
+        NUMERIS_VALUE_TOO_LARGE => b"value is too large to be written as a Roman numeral\0",
+        NUMERIS_VALUE_TOO_SMALL => b"value is too small to be written as a Roman numeral\0",
+        NUMERIS_UNPARSABLE => b"value could not be parsed\0",
+        NUMERIS_INVALID_CHARACTER => b"a character is not a Roman numeral symbol\0",
+        NUMERIS_INVALID_REPETITION => b"a symbol is repeated too many times\0",
+        NUMERIS_OUT_OF_ORDER => b"a symbol is too large for its position\0",
+        NUMERIS_EMPTY_STRING => b"no Roman numeral provided\0",
+        NUMERIS_NULL_POINTER => b"a required pointer was null\0",
+        NUMERIS_BUFFER_TOO_SMALL => b"the buffer is too small for the result\0",
+        _ => b"unknown status\0",
+    };
+    message.as_ptr().cast()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+    use std::ptr;
+
+    use super::*;
+    use crate::{integer_to_roman, MAX_VALUE, MIN_VALUE};
+
+    fn itor(val: u32, buf_len: usize) -> (i32, String) {
+        let mut buf = [0; 32];
+        let status = unsafe { numeris_itor(val, buf.as_mut_ptr(), buf_len) };
+        let numeral = unsafe { CStr::from_ptr(buf.as_ptr()) };
+        (status, numeral.to_str().unwrap().to_string())
+    }
+
+    fn rtoi(numeral: &[u8]) -> (i32, u32) {
+        let mut out = 0;
+        let status = unsafe { numeris_rtoi(numeral.as_ptr().cast(), &mut out) };
+        (status, out)
+    }
+
+    #[test]
+    fn convert_integers() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            assert_eq!(itor(val, NUMERIS_BUFFER_LEN), (NUMERIS_OK, integer_to_roman(val).unwrap()));
+        }
+        assert_eq!(itor(8, 5), (NUMERIS_OK, String::from("VIII")));
+        assert_eq!(itor(0, 16), (NUMERIS_VALUE_TOO_SMALL, String::new()));
+        assert_eq!(itor(4000, 16), (NUMERIS_VALUE_TOO_LARGE, String::new()));
+        assert_eq!(itor(8, 4), (NUMERIS_BUFFER_TOO_SMALL, String::new()));
+        assert_eq!(unsafe { numeris_itor(8, ptr::null_mut(), 16) }, NUMERIS_NULL_POINTER);
+    }
+
+    #[test]
+    fn convert_numerals() {
+        assert_eq!(rtoi(b"MCXLII\0"), (NUMERIS_OK, 1142));
+        assert_eq!(rtoi(b" xlii \0"), (NUMERIS_OK, 42));
+        assert_eq!(rtoi(b"XIV\xff\0"), (NUMERIS_INVALID_CHARACTER, 0));
+        assert_eq!(rtoi(b"IIII\0"), (NUMERIS_INVALID_REPETITION, 0));
+        assert_eq!(rtoi(b"CMM\0"), (NUMERIS_OUT_OF_ORDER, 0));
+        assert_eq!(rtoi(b"\0"), (NUMERIS_EMPTY_STRING, 0));
+        assert_eq!(unsafe { numeris_rtoi(ptr::null(), &mut 0) }, NUMERIS_NULL_POINTER);
+        assert_eq!(
+            unsafe { numeris_rtoi(b"X\0".as_ptr().cast(), ptr::null_mut()) },
+            NUMERIS_NULL_POINTER
+        );
+    }
+
+    #[test]
+    fn describe_statuses() {
+        let message = |status| unsafe { CStr::from_ptr(numeris_status_message(status)) };
+        assert_eq!(message(NUMERIS_OK).to_str(), Ok("success"));
+        assert_eq!(message(NUMERIS_EMPTY_STRING).to_str(), Ok("no Roman numeral provided"));
+        assert_eq!(message(42).to_str(), Ok("unknown status"));
+    }
+}
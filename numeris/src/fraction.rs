@@ -0,0 +1,210 @@
+use super::rtoi::roman_to_integer_at;
+use super::{integer_to_roman, Position, Result, RomanNumeralError};
+
+/// The symbol for one half (six twelfths), the _semis_.
+const SEMIS: char = 'S';
+/// The symbol for one twelfth, the _uncia_.
+const UNCIA: char = '·';
+/// Alternative symbols accepted for the _uncia_ when parsing.
+const UNCIA_ALTERNATIVES: [char; 2] = [UNCIA, '•'];
+
+/// A value made up of a whole number and a number of twelfths, as represented by Roman fractions.
+///
+/// Roman fractions are duodecimal: `S` (_semis_) represents one half, and each dot (_uncia_)
+/// represents one twelfth. For example, `IIS` is 2½ and `S··` is 8/12.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RomanFraction {
+    whole: u32,
+    twelfths: u8,
+}
+
+impl RomanFraction {
+    /// Creates a new fraction from a whole number and a number of twelfths.
+    ///
+    /// Twelfths in excess of 11 are carried over into the whole number.
+    ///
+    /// ```
+    /// use numeris::RomanFraction;
+    ///
+    /// let f = RomanFraction::new(2, 15);
+    /// assert_eq!(f.whole(), 3);
+    /// assert_eq!(f.twelfths(), 3);
+    /// ```
+    pub fn new(whole: u32, twelfths: u8) -> Self {
+        RomanFraction {
+            whole: whole.saturating_add(u32::from(twelfths / 12)),
+            twelfths: twelfths % 12,
+        }
+    }
+
+    /// The whole-number part of the fraction.
+    pub fn whole(&self) -> u32 {
+        self.whole
+    }
+
+    /// The fractional part, in twelfths (always less than 12).
+    pub fn twelfths(&self) -> u8 {
+        self.twelfths
+    }
+
+    /// The value of the fraction as a floating-point number.
+    pub fn as_f64(&self) -> f64 {
+        f64::from(self.whole) + f64::from(self.twelfths) / 12.0
+    }
+}
+
+/// Converts a [`RomanFraction`] into a string representing a Roman numeral with a fraction.
+///
+/// The whole-number part is converted as by [`integer_to_roman`], and may be zero as long as the
+/// fractional part is not. The fractional part is written as `S` for one half, followed by one
+/// middle dot (`·`) for each remaining twelfth.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{fraction_to_roman, RomanFraction};
+///
+/// assert_eq!(fraction_to_roman(RomanFraction::new(2, 6)).unwrap(), "IIS");
+/// assert_eq!(fraction_to_roman(RomanFraction::new(0, 8)).unwrap(), "S··");
+/// assert_eq!(fraction_to_roman(RomanFraction::new(12, 1)).unwrap(), "XII·");
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralErrorKind` | Reason |
+/// | ----------------------- | ------ |
+/// | [`ValueTooSmall`][a] | `fraction` is zero |
+/// | [`ValueTooLarge`][b] | the whole-number part is too large to be converted |
+///
+/// [a]: crate::RomanNumeralError::ValueTooSmall
+/// [b]: crate::RomanNumeralError::ValueTooLarge
+pub fn fraction_to_roman(fraction: RomanFraction) -> Result<String> {
+    let mut result = match (fraction.whole, fraction.twelfths) {
+        (0, 0) => return Err(RomanNumeralError::ValueTooSmall(0)),
+        (0, _) => String::new(),
+        (whole, _) => integer_to_roman(whole)?,
+    };
+    if fraction.twelfths >= 6 {
+        result.push(SEMIS);
+    }
+    result.extend(std::iter::repeat_n(UNCIA, usize::from(fraction.twelfths % 6)));
+    Ok(result)
+}
+
+/// Converts a string representing a Roman numeral with a fraction into a [`RomanFraction`].
+///
+/// The input consists of an optional whole-number part, as accepted by
+/// [`roman_to_integer`][crate::roman_to_integer], followed by an optional `S` and up to five dots.
+/// Both middle dots (`·`) and bullets (`•`) are accepted as dots. Case is ignored, and leading and
+/// trailing whitespace is trimmed.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::roman_to_fraction;
+///
+/// let f = roman_to_fraction("IIS").unwrap();
+/// assert_eq!((f.whole(), f.twelfths()), (2, 6));
+/// let f = roman_to_fraction("xs••").unwrap();
+/// assert_eq!((f.whole(), f.twelfths()), (10, 8));
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralError` | Reason |
+/// | ----------------------- | ------ |
+/// | [`Unparsable`][a] | the fractional part of `numeral` is malformed |
+/// | [`EmptyString`][b] |  `numeral` is an empty string or contains only whitespace |
+///
+/// Any error returned by [`roman_to_integer`][crate::roman_to_integer] for the whole-number part
+/// may also be returned.
+///
+/// [a]: crate::RomanNumeralError::Unparsable
+/// [b]: crate::RomanNumeralError::EmptyString
+pub fn roman_to_fraction(numeral: &str) -> Result<RomanFraction> {
+    let trimmed = numeral.trim();
+    if trimmed.is_empty() {
+        return Err(RomanNumeralError::EmptyString);
+    }
+    let whole_and_semis = trimmed.trim_end_matches(&UNCIA_ALTERNATIVES[..]);
+    let unciae = trimmed[whole_and_semis.len()..].chars().count();
+    let (whole, semis) = match whole_and_semis.strip_suffix(&['S', 's'][..]) {
+        Some(whole) => (whole, 6),
+        None => (whole_and_semis, 0),
+    };
+    if unciae > 5 || whole.ends_with(char::is_whitespace) {
+        return Err(RomanNumeralError::Unparsable(trimmed.to_uppercase()));
+    }
+    let start = Position::default().after(&numeral[..numeral.len() - numeral.trim_start().len()]);
+    let whole = if whole.is_empty() { 0 } else { roman_to_integer_at(whole, start)? };
+    Ok(RomanFraction::new(whole, semis + unciae as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fraction_to_roman, roman_to_fraction, Position, RomanFraction, RomanNumeralError};
+
+    #[test]
+    fn fraction_carries_excess_twelfths() {
+        assert_eq!(RomanFraction::new(1, 12), RomanFraction::new(2, 0));
+        assert_eq!(RomanFraction::new(u32::MAX, 24).whole(), u32::MAX);
+        assert_eq!(RomanFraction::new(2, 6).as_f64(), 2.5);
+    }
+
+    #[test]
+    fn convert_fractions_to_roman() {
+        assert_eq!(fraction_to_roman(RomanFraction::new(0, 1)).unwrap(), String::from("·"));
+        assert_eq!(fraction_to_roman(RomanFraction::new(0, 6)).unwrap(), String::from("S"));
+        let rn = fraction_to_roman(RomanFraction::new(4, 11)).unwrap();
+        assert_eq!(rn, String::from("IVS·····"));
+        assert_eq!(fraction_to_roman(RomanFraction::new(7, 0)).unwrap(), String::from("VII"));
+    }
+
+    #[test]
+    fn reject_fractions_out_of_range() {
+        assert_eq!(
+            fraction_to_roman(RomanFraction::default()),
+            Err(RomanNumeralError::ValueTooSmall(0))
+        );
+        assert_eq!(
+            fraction_to_roman(RomanFraction::new(4000, 6)),
+            Err(RomanNumeralError::ValueTooLarge(4000))
+        );
+    }
+
+    #[test]
+    fn convert_roman_to_fractions() {
+        assert_eq!(roman_to_fraction("S").unwrap(), RomanFraction::new(0, 6));
+        assert_eq!(roman_to_fraction("··").unwrap(), RomanFraction::new(0, 2));
+        assert_eq!(roman_to_fraction(" iis ").unwrap(), RomanFraction::new(2, 6));
+        assert_eq!(roman_to_fraction("XIIS·•").unwrap(), RomanFraction::new(12, 8));
+        assert_eq!(roman_to_fraction("MCXLII").unwrap(), RomanFraction::new(1142, 0));
+    }
+
+    #[test]
+    fn reject_invalid_fractions() {
+        assert_eq!(roman_to_fraction("  "), Err(RomanNumeralError::EmptyString));
+        for val in ["S······", "X S"].iter() {
+            match roman_to_fraction(val) {
+                Err(RomanNumeralError::Unparsable(_)) => (),
+                Err(e) => panic!("wrong kind of error: {:?}", e),
+                Ok(f) => panic!("unexpected ok result: {} = {:?}", val, f),
+            }
+        }
+        let pos = Position::new(0, 0);
+        assert_eq!(
+            roman_to_fraction("SS"),
+            Err(RomanNumeralError::InvalidCharacter { ch: 'S', pos })
+        );
+        let pos = Position::new(1, 1);
+        assert_eq!(
+            roman_to_fraction(" S·X"),
+            Err(RomanNumeralError::InvalidCharacter { ch: 'S', pos })
+        );
+        let pos = Position::new(4, 4);
+        assert_eq!(
+            roman_to_fraction(" IIII·"),
+            Err(RomanNumeralError::InvalidRepetition { symbol: "I", count: 4, pos })
+        );
+    }
+}
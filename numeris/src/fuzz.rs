@@ -0,0 +1,136 @@
+//! Generating numerals for fuzzing with `arbitrary`. Requires the `arbitrary` feature.
+//!
+//! Fuzzers can generate valid [`RomanNumeral`]s, as well as [`PlausibleNumeral`]s, which look like
+//! numerals but may not be valid, for exercising the parsers.
+
+use std::fmt;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{RomanNumeral, MAX_VALUE, MIN_VALUE};
+
+/// The symbols from which a [`PlausibleNumeral`] is mostly made.
+const SYMBOLS: [char; 14] = ['I', 'V', 'X', 'L', 'C', 'D', 'M', 'i', 'v', 'x', 'l', 'c', 'd', 'm'];
+
+/// Generates numerals uniformly from every valid value.
+impl<'a> Arbitrary<'a> for RomanNumeral {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let val = u.int_in_range(MIN_VALUE..=MAX_VALUE)?;
+        Ok(RomanNumeral::new(val).expect("generated a valid value"))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u16 as Arbitrary>::size_hint(depth)
+    }
+}
+
+/// A string that looks like a Roman numeral, but may not be a valid one, for fuzzing parsers.
+///
+/// A `PlausibleNumeral` is made mostly of Roman numeral symbols, in either case, with the
+/// occasional space or other character, so that fuzzers spend their time on the cases that a
+/// parser has to think about, rather than on strings that are rejected at the first character.
+/// Some are valid numerals, such as `XIV`, and some are not, such as `XIIX` or `MMCM I`.
+/// Requires the `arbitrary` feature.
+///
+/// # Examples
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use numeris::{roman_to_integer, PlausibleNumeral};
+///
+/// let mut u = Unstructured::new(&[4, 0, 9, 17, 30, 200]);
+/// let numeral = PlausibleNumeral::arbitrary(&mut u).unwrap();
+/// // This must never panic, whatever the numeral
+/// let _ = roman_to_integer(numeral.as_str());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PlausibleNumeral(String);
+
+impl PlausibleNumeral {
+    /// The generated string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Unwraps the generated string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl<'a> Arbitrary<'a> for PlausibleNumeral {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<u8>()?;
+        let mut numeral = String::with_capacity(len);
+        for _ in 0..len {
+            let ch = match u8::arbitrary(u)? {
+                // Mostly upper-case symbols, as in most real input
+                choice @ 0..=195 => SYMBOLS[usize::from(choice) % 7],
+                choice @ 196..=237 => SYMBOLS[7 + usize::from(choice) % 7],
+                238..=245 => ' ',
+                _ => char::arbitrary(u)?,
+            };
+            numeral.push(ch);
+        }
+        Ok(PlausibleNumeral(numeral))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl fmt::Display for PlausibleNumeral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for PlausibleNumeral {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{roman_to_integer, PlausibleNumeral, RomanNumeral};
+
+    /// Pseudo-random bytes, as a fuzzer might provide.
+    fn bytes(seed: u32, len: usize) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn generate_valid_numerals() {
+        let data = bytes(1142, 20_000);
+        let mut u = Unstructured::new(&data);
+        let numerals: Vec<RomanNumeral> =
+            (0..10_000).map(|_| RomanNumeral::arbitrary(&mut u).unwrap()).collect();
+        assert!(numerals.iter().any(|rn| *rn < 100) && numerals.iter().any(|rn| *rn > 3900));
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(RomanNumeral::arbitrary(&mut u).unwrap().value(), 1);
+    }
+
+    #[test]
+    fn generate_plausible_numerals() {
+        let (mut valid, mut invalid) = (0, 0);
+        for seed in 0..1000 {
+            let data = bytes(seed, 64);
+            let numeral = PlausibleNumeral::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+            match roman_to_integer(numeral.as_str()) {
+                Ok(_) => valid += 1,
+                Err(_) => invalid += 1,
+            }
+        }
+        assert!(valid > 0 && invalid > 0, "{} valid, {} invalid", valid, invalid);
+    }
+}
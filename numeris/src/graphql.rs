@@ -0,0 +1,79 @@
+//! A GraphQL scalar for [`RomanNumeral`]s, with `async-graphql`. Requires the `async-graphql`
+//! feature.
+//!
+//! A `RomanNumeral` is a scalar named `RomanNumeral`, which is written as a string, so that it can
+//! be used directly as an argument or a field in a schema.
+
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use super::RomanNumeral;
+
+/// A Roman numeral, between I and MMMCMXCIX. Input numerals are case-insensitive, and output
+/// numerals are always in their canonical, upper-case, form.
+#[Scalar(name = "RomanNumeral")]
+impl ScalarType for RomanNumeral {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(numeral) => Ok(numeral.parse()?),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::futures_util::FutureExt;
+    use async_graphql::{value, EmptyMutation, EmptySubscription, Object, Response, Schema};
+
+    use crate::RomanNumeral;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn next(&self, numeral: RomanNumeral) -> Option<RomanNumeral> {
+            numeral.succ()
+        }
+    }
+
+    fn execute(query: &str) -> Response {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        schema.execute(query).now_or_never().expect("resolvers never wait")
+    }
+
+    #[test]
+    fn accept_and_return_numerals() {
+        let response = execute(r#"{ next(numeral: "xlii") }"#);
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(response.data, value!({ "next": "XLIII" }));
+        assert_eq!(execute(r#"{ next(numeral: "MMMCMXCIX") }"#).data, value!({ "next": null }));
+    }
+
+    #[test]
+    fn report_input_errors() {
+        let response = execute(r#"{ next(numeral: "IIII") }"#);
+        assert_eq!(
+            response.errors[0].message,
+            "Failed to parse \"RomanNumeral\": I is repeated 4 times in a row"
+        );
+        let response = execute("{ next(numeral: 4) }");
+        assert_eq!(
+            response.errors[0].message,
+            "Invalid value for argument \"numeral\", expected type \"RomanNumeral\""
+        );
+    }
+
+    #[test]
+    fn describe_scalar_in_schema() {
+        let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+        assert!(sdl.contains("scalar RomanNumeral"));
+    }
+}
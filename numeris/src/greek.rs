@@ -0,0 +1,306 @@
+//! Ionian Greek numerals, also known as Milesian or alphabetic numerals. Requires the `greek`
+//! feature.
+//!
+//! Each of the units 1–9, the tens 10–90 and the hundreds 100–900 is written as a single letter
+//! of the Greek alphabet, including the archaic letters stigma (`ϛ`), koppa (`ϟ`) and sampi
+//! (`ϡ`). The thousands 1000–9000 are written as the letters for the units, preceded by a lower
+//! keraia (`͵`). The letters are written from the largest value down, and the numeral is marked
+//! with a keraia (`ʹ`) after its last letter, e.g. `ιβʹ` is 12 and `͵αρμβʹ` is 1142.
+//!
+//! # Examples
+//!
+//! ```
+//! use numeris::greek::{greek_to_integer, integer_to_greek};
+//!
+//! assert_eq!(integer_to_greek(12).unwrap(), "ιβʹ");
+//! assert_eq!(integer_to_greek(1142).unwrap(), "͵αρμβʹ");
+//! assert_eq!(greek_to_integer("ΙΒʹ").unwrap(), 12);
+//! assert_eq!(greek_to_integer("͵αρμβ").unwrap(), 1142);
+//! ```
+
+use super::{NumeralSystem, Position, Result, RomanNumeralError};
+
+/// The minimum value supported for Greek numerals
+pub const MIN_VALUE: u32 = 1;
+/// The maximum value supported for Greek numerals, as larger values need myriads
+pub const MAX_VALUE: u32 = 9999;
+
+/// The keraia, which marks the end of a numeral.
+const KERAIA: char = '\u{0374}';
+/// Alternative characters accepted as the keraia when parsing: the modifier letter prime, the
+/// prime and the apostrophe, which are often used in its place.
+const KERAIA_ALTERNATIVES: [char; 4] = [KERAIA, '\u{02B9}', '\u{2032}', '\''];
+/// The lower keraia, which marks a thousand.
+const LOWER_KERAIA: char = '\u{0375}';
+
+/// The letters for the units, tens and hundreds, from the smallest value up.
+const LETTERS: [(&str, u32); 27] = [
+    ("α", 1),
+    ("β", 2),
+    ("γ", 3),
+    ("δ", 4),
+    ("ε", 5),
+    ("ϛ", 6),
+    ("ζ", 7),
+    ("η", 8),
+    ("θ", 9),
+    ("ι", 10),
+    ("κ", 20),
+    ("λ", 30),
+    ("μ", 40),
+    ("ν", 50),
+    ("ξ", 60),
+    ("ο", 70),
+    ("π", 80),
+    ("ϟ", 90),
+    ("ρ", 100),
+    ("σ", 200),
+    ("τ", 300),
+    ("υ", 400),
+    ("φ", 500),
+    ("χ", 600),
+    ("ψ", 700),
+    ("ω", 800),
+    ("ϡ", 900),
+];
+
+/// The thousands, from 1000 up.
+const THOUSANDS: [&str; 9] = ["͵α", "͵β", "͵γ", "͵δ", "͵ε", "͵ϛ", "͵ζ", "͵η", "͵θ"];
+
+/// Letters that are accepted in place of the archaic letters when parsing: the final sigma and
+/// the digraph `στ` for stigma, and the archaic form of koppa.
+const LETTER_ALTERNATIVES: [(char, char); 2] = [('ς', 'ϛ'), ('ϙ', 'ϟ')];
+
+/// Ionian Greek numerals, written by [`integer_to_greek`] and parsed by [`greek_to_integer`], as a
+/// [`NumeralSystem`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Greek;
+
+impl NumeralSystem for Greek {
+    fn id(&self) -> &'static str {
+        "greek"
+    }
+
+    fn name(&self) -> &'static str {
+        "Ionian Greek"
+    }
+
+    fn min_value(&self) -> u32 {
+        MIN_VALUE
+    }
+
+    fn max_value(&self) -> u32 {
+        MAX_VALUE
+    }
+
+    fn format(&self, val: u32) -> Result<String> {
+        integer_to_greek(val)
+    }
+
+    fn parse(&self, numeral: &str) -> Result<u32> {
+        greek_to_integer(numeral)
+    }
+}
+
+/// Converts an integer into a string representing an Ionian Greek numeral.
+///
+/// The numeral is written in lower-case, and ends with a keraia (`ʹ`, U+0374).
+///
+/// # Examples
+///
+/// ```
+/// use numeris::greek::integer_to_greek;
+///
+/// assert_eq!(integer_to_greek(6).unwrap(), "ϛʹ");
+/// assert_eq!(integer_to_greek(999).unwrap(), "ϡϟθʹ");
+/// assert_eq!(integer_to_greek(2024).unwrap(), "͵βκδʹ");
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralErrorKind` | Reason |
+/// | ----------------------- | ------ |
+/// | [`ValueTooSmall`][a] | `val` is 0 |
+/// | [`ValueTooLarge`][b] | `val` is greater than [`MAX_VALUE`] |
+///
+/// [a]: crate::RomanNumeralError::ValueTooSmall
+/// [b]: crate::RomanNumeralError::ValueTooLarge
+pub fn integer_to_greek(val: u32) -> Result<String> {
+    if val < MIN_VALUE {
+        return Err(RomanNumeralError::ValueTooSmall(val));
+    } else if val > MAX_VALUE {
+        return Err(RomanNumeralError::ValueTooLarge(val));
+    }
+    let mut numeral = String::new();
+    if val >= 1000 {
+        numeral.push_str(THOUSANDS[(val / 1000 - 1) as usize]);
+    }
+    for place in (0..3).rev() {
+        let digit = val / 10u32.pow(place) % 10;
+        if digit > 0 {
+            numeral.push_str(LETTERS[(place * 9 + digit - 1) as usize].0);
+        }
+    }
+    numeral.push(KERAIA);
+    Ok(numeral)
+}
+
+/// Converts a string representing an Ionian Greek numeral into an integer.
+///
+/// The keraia at the end of the numeral is optional, and the modifier letter prime (`ʹ`), prime
+/// (`′`) and apostrophe are accepted in its place. Case is ignored, and leading and trailing
+/// whitespace is trimmed. As well as stigma (`ϛ`), the final sigma (`ς`) and the digraph `στ`
+/// are accepted for 6, as they are commonly used in its place, and the archaic koppa (`ϙ`) is
+/// accepted for 90.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::greek::greek_to_integer;
+///
+/// assert_eq!(greek_to_integer("ρκγʹ").unwrap(), 123);
+/// assert_eq!(greek_to_integer("στ'").unwrap(), 6);
+/// assert_eq!(greek_to_integer("͵ΒΚΔ").unwrap(), 2024);
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralError` | Reason |
+/// | ----------------------- | ------ |
+/// | [`InvalidCharacter`][a] | `numeral` contains a character that is not a Greek numeral letter |
+/// | [`OutOfOrder`][b] | `numeral` contains a letter that is too large for its position |
+/// | [`EmptyString`][c] |  `numeral` is an empty string or contains only whitespace |
+///
+/// [a]: crate::RomanNumeralError::InvalidCharacter
+/// [b]: crate::RomanNumeralError::OutOfOrder
+/// [c]: crate::RomanNumeralError::EmptyString
+pub fn greek_to_integer(numeral: &str) -> Result<u32> {
+    let trimmed = numeral.trim();
+    let letters = trimmed.strip_suffix(&KERAIA_ALTERNATIVES[..]).unwrap_or(trimmed);
+    if letters.is_empty() {
+        return Err(RomanNumeralError::EmptyString);
+    }
+    let start = Position::default().after(&numeral[..numeral.len() - numeral.trim_start().len()]);
+    let mut total = 0;
+    let mut expected_max = MAX_VALUE;
+    let mut chars = letters.char_indices().peekable();
+    while let Some((byte, ch)) = chars.next() {
+        let pos = start.after(&letters[..byte]);
+        let invalid = RomanNumeralError::InvalidCharacter { ch, pos };
+        let (found, value) = if ch == LOWER_KERAIA {
+            match chars.next().and_then(|(_, unit)| letter(unit)) {
+                Some((_, value)) if value < 10 => (THOUSANDS[value as usize - 1], value * 1000),
+                _ => return Err(invalid),
+            }
+        } else if is_letter(ch, 'σ') && chars.peek().is_some_and(|&(_, next)| is_letter(next, 'τ'))
+        {
+            chars.next();
+            LETTERS[5]
+        } else {
+            letter(ch).ok_or(invalid)?
+        };
+        if value > expected_max {
+            return Err(RomanNumeralError::OutOfOrder { found, expected_max, pos });
+        }
+        total += value;
+        expected_max = place_of(value) - 1;
+    }
+    Ok(total)
+}
+
+/// The letter for `ch`, in either case, with its value.
+fn letter(ch: char) -> Option<(&'static str, u32)> {
+    let ch = ch.to_lowercase().next().unwrap_or(ch);
+    let ch = LETTER_ALTERNATIVES
+        .iter()
+        .find(|&&(alternative, _)| alternative == ch)
+        .map_or(ch, |&(_, letter)| letter);
+    LETTERS.iter().copied().find(|(letter, _)| letter.starts_with(ch))
+}
+
+fn is_letter(ch: char, letter: char) -> bool {
+    ch.to_lowercase().eq(std::iter::once(letter))
+}
+
+/// The power of ten of the most significant digit of `value`, e.g. 100 for 300.
+fn place_of(value: u32) -> u32 {
+    let mut place = 1;
+    while place * 10 <= value {
+        place *= 10;
+    }
+    place
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{greek_to_integer, integer_to_greek, MAX_VALUE, MIN_VALUE};
+    use crate::{Position, RomanNumeralError};
+
+    #[test]
+    fn convert_integers_to_greek() {
+        assert_eq!(integer_to_greek(1).unwrap(), "αʹ");
+        assert_eq!(integer_to_greek(10).unwrap(), "ιʹ");
+        assert_eq!(integer_to_greek(11).unwrap(), "ιαʹ");
+        assert_eq!(integer_to_greek(666).unwrap(), "χξϛʹ");
+        assert_eq!(integer_to_greek(1000).unwrap(), "͵αʹ");
+        assert_eq!(integer_to_greek(9999).unwrap(), "͵θϡϟθʹ");
+    }
+
+    #[test]
+    fn reject_values_out_of_range() {
+        assert_eq!(integer_to_greek(0), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(integer_to_greek(10_000), Err(RomanNumeralError::ValueTooLarge(10_000)));
+    }
+
+    #[test]
+    fn round_trip_all_values() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let numeral = integer_to_greek(val).unwrap();
+            assert_eq!(greek_to_integer(&numeral), Ok(val), "{}", numeral);
+            assert_eq!(greek_to_integer(&numeral.to_uppercase()), Ok(val), "{}", numeral);
+        }
+    }
+
+    #[test]
+    fn accept_alternative_letters() {
+        assert_eq!(greek_to_integer(" ιβ′ "), Ok(12));
+        assert_eq!(greek_to_integer("ιβ"), Ok(12));
+        assert_eq!(greek_to_integer("ις"), Ok(16));
+        assert_eq!(greek_to_integer("ΙΣΤʹ"), Ok(16));
+        assert_eq!(greek_to_integer("ϙθ"), Ok(99));
+        assert_eq!(greek_to_integer("ϞΘ"), Ok(99));
+    }
+
+    #[test]
+    fn reject_invalid_numerals() {
+        assert_eq!(greek_to_integer("ʹ"), Err(RomanNumeralError::EmptyString));
+        assert_eq!(
+            greek_to_integer("ιω"),
+            Err(RomanNumeralError::OutOfOrder {
+                found: "ω",
+                expected_max: 9,
+                pos: Position::new(2, 1)
+            })
+        );
+        assert_eq!(
+            greek_to_integer("αα"),
+            Err(RomanNumeralError::OutOfOrder {
+                found: "α",
+                expected_max: 0,
+                pos: Position::new(2, 1)
+            })
+        );
+        assert_eq!(
+            greek_to_integer("͵ι"),
+            Err(RomanNumeralError::InvalidCharacter { ch: '͵', pos: Position::new(0, 0) })
+        );
+        assert_eq!(
+            greek_to_integer("ιʹβ"),
+            Err(RomanNumeralError::InvalidCharacter { ch: 'ʹ', pos: Position::new(2, 1) })
+        );
+        assert_eq!(
+            greek_to_integer("XII"),
+            Err(RomanNumeralError::InvalidCharacter { ch: 'X', pos: Position::new(0, 0) })
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::convert::TryInto;
+use std::iter::FusedIterator;
+
+use super::{integer_to_roman, roman_to_integer, Result};
+
+/// Extension trait for converting iterators of values lazily.
+///
+/// This is implemented for all iterators. [`to_roman`][RomanIteratorExt::to_roman] is available
+/// when the items are integers, and [`to_integers`][RomanIteratorExt::to_integers] when they are
+/// strings. Each item is converted only when it is reached, so nothing is collected along the way.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::prelude::*;
+///
+/// let mut numerals = (1..=3).to_roman();
+/// assert_eq!(numerals.next().unwrap().unwrap(), "I");
+/// let values: Vec<u32> = "X XX XXX".split(' ').to_integers().map(Result::unwrap).collect();
+/// assert_eq!(values, [10, 20, 30]);
+/// ```
+pub trait RomanIteratorExt: Iterator + Sized {
+    /// Converts each integer into a Roman numeral, as by [`integer_to_roman`].
+    #[allow(clippy::wrong_self_convention)]
+    fn to_roman(self) -> IntegersToRomans<Self>
+    where
+        Self::Item: TryInto<u32> + PartialOrd + Default,
+    {
+        IntegersToRomans { iter: self }
+    }
+
+    /// Converts each string, representing a Roman numeral, into an integer, as by
+    /// [`roman_to_integer`].
+    #[allow(clippy::wrong_self_convention)]
+    fn to_integers(self) -> RomansToIntegers<Self>
+    where
+        Self::Item: AsRef<str>,
+    {
+        RomansToIntegers { iter: self }
+    }
+}
+
+impl<I: Iterator> RomanIteratorExt for I {}
+
+/// An iterator that converts integers into Roman numerals.
+///
+/// This is created by [`RomanIteratorExt::to_roman`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntegersToRomans<I> {
+    iter: I,
+}
+
+impl<I> Iterator for IntegersToRomans<I>
+where
+    I: Iterator,
+    I::Item: TryInto<u32> + PartialOrd + Default,
+{
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(integer_to_roman)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for IntegersToRomans<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: TryInto<u32> + PartialOrd + Default,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(integer_to_roman)
+    }
+}
+
+impl<I> ExactSizeIterator for IntegersToRomans<I>
+where
+    I: ExactSizeIterator,
+    I::Item: TryInto<u32> + PartialOrd + Default,
+{
+}
+
+impl<I> FusedIterator for IntegersToRomans<I>
+where
+    I: FusedIterator,
+    I::Item: TryInto<u32> + PartialOrd + Default,
+{
+}
+
+/// An iterator that converts strings representing Roman numerals into integers.
+///
+/// This is created by [`RomanIteratorExt::to_integers`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RomansToIntegers<I> {
+    iter: I,
+}
+
+impl<I> Iterator for RomansToIntegers<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<u32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|numeral| roman_to_integer(numeral.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for RomansToIntegers<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<str>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|numeral| roman_to_integer(numeral.as_ref()))
+    }
+}
+
+impl<I> ExactSizeIterator for RomansToIntegers<I>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+impl<I> FusedIterator for RomansToIntegers<I>
+where
+    I: FusedIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::RomanNumeralError;
+
+    #[test]
+    fn convert_integers_lazily() {
+        let numerals: Vec<_> = vec![1u8, 4, 0].into_iter().to_roman().collect();
+        assert_eq!(
+            numerals,
+            [
+                Ok(String::from("I")),
+                Ok(String::from("IV")),
+                Err(RomanNumeralError::ValueTooSmall(0))
+            ]
+        );
+        let mut numerals = (1..4000).to_roman();
+        assert_eq!(numerals.len(), 3999);
+        assert_eq!(numerals.next_back().unwrap().unwrap(), "MMMCMXCIX");
+    }
+
+    #[test]
+    fn convert_numerals_lazily() {
+        let owned = [String::from("ix"), String::from("")];
+        let values: Vec<_> = owned.iter().to_integers().collect();
+        assert_eq!(values, [Ok(9), Err(RomanNumeralError::EmptyString)]);
+        let values: Vec<_> = ["I", "II", "III"].iter().to_integers().rev().collect();
+        assert_eq!(values, [Ok(3), Ok(2), Ok(1)]);
+    }
+
+    #[test]
+    fn conversion_is_lazy() {
+        let mut seen = 0;
+        let mut numerals = (1..).inspect(|_| seen += 1).to_roman();
+        assert_eq!(numerals.nth(9).unwrap().unwrap(), "X");
+        drop(numerals);
+        assert_eq!(seen, 10);
+    }
+}
@@ -1,17 +1,22 @@
-use std::collections::HashMap;
-use std::iter::FromIterator;
+use std::convert::TryInto;
+#[cfg(feature = "precomputed")]
+use std::sync::OnceLock;
 
-use itertools;
-use itertools::Itertools;
-use lazy_static::lazy_static;
+use super::{Result, RomanAtom, RomanNumeralError, ATOMS, MAX_VALUE, MIN_VALUE};
 
-use super::{Result, RomanNumeralError, ATOMS, MAX_VALUE, MIN_VALUE};
+/// How integers are converted into numerals, as recorded by `tracing`.
+#[cfg(feature = "tracing")]
+const STYLE: &str = if cfg!(feature = "precomputed") { "table" } else { "computed" };
 
 /// Converts an integer into a string representing a Roman Numeral.
 ///
 /// The input must be greater than or equal to [`MIN_VALUE`] and less than or equal to
 /// [`MAX_VALUE`]. The Roman numeral will be returned in upper-case characters.
 ///
+/// Any of the primitive integer types can be converted directly, without first casting to `u32`.
+/// Values that cannot be represented as a `u32` are reported using the nearest `u32` value: `0`
+/// for negative values, and `u32::MAX` for values that are too large.
+///
 /// # Examples
 ///
 /// ### Normal usage
@@ -20,6 +25,8 @@ use super::{Result, RomanNumeralError, ATOMS, MAX_VALUE, MIN_VALUE};
 ///
 /// let rn = integer_to_roman(1142).unwrap();
 /// assert_eq!(rn, "MCXLII");
+/// let rn = integer_to_roman(42u8).unwrap();
+/// assert_eq!(rn, "XLII");
 /// ```
 ///
 /// ### Value too small
@@ -53,54 +60,235 @@ use super::{Result, RomanNumeralError, ATOMS, MAX_VALUE, MIN_VALUE};
 ///
 /// [a]: crate::RomanNumeralError::ValueTooSmall
 /// [b]: crate::RomanNumeralError::ValueTooLarge
-pub fn integer_to_roman(val: u32) -> Result<String> {
+pub fn integer_to_roman<T>(val: T) -> Result<String>
+where
+    T: TryInto<u32> + PartialOrd + Default,
+{
+    u32_to_roman(to_u32(val)?)
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "trace",
+        skip_all,
+        fields(value = val, style = STYLE),
+        ret(level = "trace"),
+        err(level = "debug", Debug)
+    )
+)]
+fn u32_to_roman(val: u32) -> Result<String> {
+    #[cfg(feature = "precomputed")]
+    {
+        integer_to_roman_str(val).map(String::from)
+    }
+    #[cfg(not(feature = "precomputed"))]
+    {
+        check_range(val)?;
+        Ok(build_roman(val))
+    }
+}
+
+/// Converts an integer into a string representing a Roman numeral, without checking its range.
+///
+/// This is a faster alternative to [`integer_to_roman`] for values that the caller has already
+/// checked are greater than or equal to [`MIN_VALUE`] and less than or equal to [`MAX_VALUE`].
+/// Values outside of that range do not cause undefined behaviour or panics, but the result is
+/// meaningless: `0` gives an empty string, and larger values give as many `M`s as are needed.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::integer_to_roman_unchecked;
+///
+/// assert_eq!(integer_to_roman_unchecked(1142), "MCXLII");
+/// ```
+pub fn integer_to_roman_unchecked(val: u32) -> String {
+    build_roman(val)
+}
+
+fn build_roman(val: u32) -> String {
+    let mut result = String::with_capacity(numeral_len(val));
+    result.extend(atoms_of(val).map(|rn| rn.symbol));
+    result
+}
+
+/// The atoms of the canonical numeral for `val`, which must already be in range.
+pub(crate) fn atoms_of(mut val: u32) -> impl Iterator<Item = &'static RomanAtom> {
+    ATOMS.iter().flat_map(move |rn| {
+        let count = val / rn.value;
+        val %= rn.value;
+        std::iter::repeat_n(rn, count as usize)
+    })
+}
+
+/// Converts an integer into a Roman numeral from a table of all of the numerals.
+///
+/// The input must be greater than or equal to [`MIN_VALUE`] and less than or equal to
+/// [`MAX_VALUE`]. The table is built the first time it is used, after which each conversion is
+/// just a lookup, with no allocation. Requires the `precomputed` feature.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::integer_to_roman_str;
+///
+/// let rn: &'static str = integer_to_roman_str(1142).unwrap();
+/// assert_eq!(rn, "MCXLII");
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralErrorKind` | Reason |
+/// | ----------------------- | ------ |
+/// | [`ValueTooSmall`][a] | `val` is too small to be converted to a Roman numeral |
+/// | [`ValueTooLarge`][b] |  `val` is too large to be converted to a Roman numeral |
+///
+/// [a]: crate::RomanNumeralError::ValueTooSmall
+/// [b]: crate::RomanNumeralError::ValueTooLarge
+#[cfg(feature = "precomputed")]
+pub fn integer_to_roman_str(val: u32) -> Result<&'static str> {
+    check_range(val)?;
+    let table = PRECOMPUTED.get_or_init(|| (MIN_VALUE..=MAX_VALUE).map(build_roman).collect());
+    Ok(&table[(val - MIN_VALUE) as usize])
+}
+
+/// Splits an integer into the atoms of its canonical Roman numeral, in order.
+///
+/// The input must be in the same range, and of the same types, as accepted by
+/// [`integer_to_roman`]. Joining the symbols of the atoms gives the same numeral that
+/// [`integer_to_roman`] returns.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::decompose;
+///
+/// let atoms = decompose(1142).unwrap();
+/// let symbols: Vec<&str> = atoms.iter().map(|atom| atom.symbol()).collect();
+/// assert_eq!(symbols, ["M", "C", "XL", "I", "I"]);
+/// assert_eq!(atoms[2].value(), 40);
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`integer_to_roman`].
+pub fn decompose<T>(val: T) -> Result<Vec<RomanAtom>>
+where
+    T: TryInto<u32> + PartialOrd + Default,
+{
+    let val = to_u32(val)?;
+    check_range(val)?;
+    Ok(atoms_of(val).copied().collect())
+}
+
+/// Calculates the number of characters in the Roman numeral for an integer, without building it.
+///
+/// The input must be in the same range, and of the same types, as accepted by
+/// [`integer_to_roman`]. The result is always the length of the string that [`integer_to_roman`]
+/// would return.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::roman_len;
+///
+/// assert_eq!(roman_len(1142).unwrap(), 6);
+/// assert_eq!(roman_len(3888).unwrap(), "MMMDCCCLXXXVIII".len());
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`integer_to_roman`].
+pub fn roman_len<T>(val: T) -> Result<usize>
+where
+    T: TryInto<u32> + PartialOrd + Default,
+{
+    let val = to_u32(val)?;
+    check_range(val)?;
+    Ok(numeral_len(val))
+}
+
+/// The length of the canonical numeral for `val`, which must already be in range.
+pub(crate) fn numeral_len(mut val: u32) -> usize {
+    // The number of symbols needed to write each decimal digit, e.g. 3 for `VII`
+    const DIGIT_LENGTHS: [usize; 10] = [0, 1, 2, 3, 2, 1, 2, 3, 4, 2];
+    let mut len = 0;
+    while val > 0 {
+        len += DIGIT_LENGTHS[(val % 10) as usize];
+        val /= 10;
+    }
+    len
+}
+
+/// Converts any integer into a `u32`, reporting values that cannot be represented using the
+/// nearest `u32` value. Values that fit still need to be checked with [`check_range`].
+pub(crate) fn to_u32<T>(val: T) -> Result<u32>
+where
+    T: TryInto<u32> + PartialOrd + Default,
+{
+    let is_negative = val < T::default();
+    match val.try_into() {
+        Ok(val) => Ok(val),
+        Err(_) if is_negative => Err(RomanNumeralError::ValueTooSmall(0)),
+        Err(_) => Err(RomanNumeralError::ValueTooLarge(u32::MAX)),
+    }
+}
+
+pub(crate) fn check_range(val: u32) -> Result<()> {
     if val < MIN_VALUE {
         Err(RomanNumeralError::ValueTooSmall(val))
     } else if val > MAX_VALUE {
         Err(RomanNumeralError::ValueTooLarge(val))
     } else {
-        let result = itertools::unfold(val, digit_extractor)
-            .filter_map(|digit| VALUES_TO_SYMBOLS.get(&digit))
-            .join("");
-        Ok(result)
+        Ok(())
     }
 }
 
-fn digit_extractor(seed: &mut u32) -> Option<u32> {
-    if *seed == 0 {
-        return None;
+/// Converts a signed integer into a string representing a Roman numeral.
+///
+/// Negative values are rendered with a leading minus sign (`-`); positive values are rendered
+/// exactly as [`integer_to_roman`] would render them. The magnitude of the input must be greater
+/// than or equal to [`MIN_VALUE`] and less than or equal to [`MAX_VALUE`].
+///
+/// # Examples
+///
+/// ```
+/// use numeris::signed_integer_to_roman;
+///
+/// assert_eq!(signed_integer_to_roman(14).unwrap(), "XIV");
+/// assert_eq!(signed_integer_to_roman(-14).unwrap(), "-XIV");
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralErrorKind` | Reason |
+/// | ----------------------- | ------ |
+/// | [`ValueTooSmall`][a] | `val` is zero |
+/// | [`ValueTooLarge`][b] | the magnitude of `val` is too large to be converted |
+///
+/// [a]: crate::RomanNumeralError::ValueTooSmall
+/// [b]: crate::RomanNumeralError::ValueTooLarge
+pub fn signed_integer_to_roman(val: i32) -> Result<String> {
+    let numeral = u32_to_roman(val.unsigned_abs())?;
+    if val < 0 {
+        Ok(format!("-{}", numeral))
+    } else {
+        Ok(numeral)
     }
-    let next_digit = DIGITS.iter().find(|digit| *seed >= **digit).unwrap_or(&1);
-    *seed = *seed - *next_digit;
-    Some(*next_digit)
 }
 
-lazy_static! {
-    static ref VALUES_TO_SYMBOLS: HashMap<u32, &'static str> =
-        HashMap::from_iter(ATOMS.iter().map(|rn| (rn.value, rn.symbol)));
-    static ref DIGITS: Vec<u32> = ATOMS.iter().map(|rn| rn.value).collect_vec();
-}
+/// The numerals for every value, from [`MIN_VALUE`] up, built by [`integer_to_roman_str`] the
+/// first time it is used.
+#[cfg(feature = "precomputed")]
+static PRECOMPUTED: OnceLock<Vec<String>> = OnceLock::new();
 
 #[cfg(test)]
 mod tests {
-    use crate::{integer_to_roman, RomanNumeralError, MAX_VALUE, MIN_VALUE};
-
-    use super::{DIGITS, VALUES_TO_SYMBOLS};
-
-    #[test]
-    fn check_digits() {
-        assert_eq!(500, DIGITS[2]);
-        assert_eq!(40, DIGITS[7]);
-        assert_eq!(5, DIGITS[10]);
-    }
-
-    #[test]
-    fn check_values_to_symbols() {
-        assert_eq!(&"CM", VALUES_TO_SYMBOLS.get(&900).unwrap());
-        assert_eq!(&"CD", VALUES_TO_SYMBOLS.get(&400).unwrap());
-        assert_eq!(&"C", VALUES_TO_SYMBOLS.get(&100).unwrap());
-        assert_eq!(&"IX", VALUES_TO_SYMBOLS.get(&9).unwrap());
-    }
+    use crate::{
+        decompose, integer_to_roman, integer_to_roman_unchecked, roman_len,
+        signed_integer_to_roman, RomanNumeralError, MAX_VALUE, MIN_VALUE,
+    };
 
     #[test]
     fn reject_values_less_than_min() {
@@ -120,6 +308,93 @@ mod tests {
         };
     }
 
+    #[test]
+    fn unchecked_matches_integer_to_roman() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            assert_eq!(integer_to_roman_unchecked(val), integer_to_roman(val).unwrap());
+        }
+        assert_eq!(integer_to_roman_unchecked(0), "");
+        assert_eq!(integer_to_roman_unchecked(5000), "MMMMM");
+    }
+
+    #[test]
+    fn convert_signed_values() {
+        assert_eq!(signed_integer_to_roman(42).unwrap(), String::from("XLII"));
+        assert_eq!(signed_integer_to_roman(-42).unwrap(), String::from("-XLII"));
+        assert_eq!(signed_integer_to_roman(-3999).unwrap(), String::from("-MMMCMXCIX"));
+    }
+
+    #[test]
+    fn reject_signed_values_out_of_range() {
+        assert_eq!(signed_integer_to_roman(0), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(signed_integer_to_roman(-4000), Err(RomanNumeralError::ValueTooLarge(4000)));
+        assert_eq!(
+            signed_integer_to_roman(i32::MIN),
+            Err(RomanNumeralError::ValueTooLarge(2_147_483_648))
+        );
+    }
+
+    #[test]
+    fn convert_other_integer_types() {
+        assert_eq!(integer_to_roman(14u8).unwrap(), String::from("XIV"));
+        assert_eq!(integer_to_roman(1999u16).unwrap(), String::from("MCMXCIX"));
+        assert_eq!(integer_to_roman(3999u64).unwrap(), String::from("MMMCMXCIX"));
+        assert_eq!(integer_to_roman(7usize).unwrap(), String::from("VII"));
+        assert_eq!(integer_to_roman(9i64).unwrap(), String::from("IX"));
+    }
+
+    #[test]
+    fn reject_other_integer_types_out_of_range() {
+        assert_eq!(integer_to_roman(0u8), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(integer_to_roman(-5i32), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(integer_to_roman(i128::MIN), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(integer_to_roman(4000u16), Err(RomanNumeralError::ValueTooLarge(4000)));
+        assert_eq!(integer_to_roman(1u64 << 40), Err(RomanNumeralError::ValueTooLarge(u32::MAX)));
+    }
+
+    #[test]
+    fn decompose_matches_integer_to_roman() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let atoms = decompose(val).unwrap();
+            let symbols: String = atoms.iter().map(|atom| atom.symbol()).collect();
+            assert_eq!(symbols, integer_to_roman(val).unwrap());
+            assert_eq!(atoms.iter().map(|atom| atom.value()).sum::<u32>(), val);
+        }
+    }
+
+    #[test]
+    fn reject_decomposing_values_out_of_range() {
+        assert_eq!(decompose(0), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(decompose(-1i8), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(decompose(4000), Err(RomanNumeralError::ValueTooLarge(4000)));
+    }
+
+    #[test]
+    fn roman_len_matches_integer_to_roman() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            assert_eq!(roman_len(val).unwrap(), integer_to_roman(val).unwrap().len(), "{}", val);
+        }
+    }
+
+    #[cfg(feature = "precomputed")]
+    #[test]
+    fn precomputed_table_matches_conversion() {
+        use super::{build_roman, integer_to_roman_str};
+
+        for val in MIN_VALUE..=MAX_VALUE {
+            assert_eq!(integer_to_roman_str(val).unwrap(), build_roman(val));
+        }
+        assert_eq!(integer_to_roman_str(0), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(integer_to_roman_str(4000), Err(RomanNumeralError::ValueTooLarge(4000)));
+    }
+
+    #[test]
+    fn reject_roman_len_out_of_range() {
+        assert_eq!(roman_len(0u8), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(roman_len(-7i64), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(roman_len(4000), Err(RomanNumeralError::ValueTooLarge(4000)));
+    }
+
     mod simple {
         use super::integer_to_roman;
 
@@ -4,13 +4,158 @@
 //!
 //! The entry points are two functions, [`integer_to_roman`] and [`roman_to_integer`], which
 //! convert between integral values and string-representations of Roman numerals. See the
-//! documentation on each function for details.
+//! documentation on each function for details. Variants of them accept bytes
+//! ([`roman_to_integer_bytes`]), signed values ([`signed_integer_to_roman`]), non-canonical
+//! numerals ([`roman_to_integer_lenient`]) and values that have already been validated
+//! ([`integer_to_roman_unchecked`]), and [`roman_to_integer_with_suggestions`] suggests the
+//! numerals that were most likely meant when a numeral is invalid.
+//!
+//! The [`RomanNumeral`] type represents a valid Roman numeral as a value, and [`RomanRange`] and
+//! [`roman_table`] list ranges of them. The [`prelude`] module re-exports the extension traits
+//! that allow conversions to be written as methods, e.g. `42.to_roman()`.
+//!
+//! Beyond single conversions, the crate handles batches of values ([`integers_to_romans`]),
+//! numerals within larger texts ([`scan`], [`arabize_text`] and [`RomanRewriter`]), fractions
+//! ([`RomanFraction`]), years _ab urbe condita_ ([`Year`]), Latin number words
+//! ([`integer_to_latin_words`]), the forms of the spreadsheet `ROMAN` function
+//! ([`SpreadsheetForm`]), and other numeral systems ([`NumeralSystem`]).
+//! [`explain`][explain()] breaks a conversion down into its steps, for teaching how Roman
+//! numerals are read.
+//!
+//! # Optional features
+//!
+//! Integrations with other crates are enabled by Cargo features, each of which is described in
+//! `Cargo.toml` and documented on the items that it adds. Among them, `serde`, `rocket`, `sqlx`,
+//! `async-graphql`, `rkyv` and `defmt` allow [`RomanNumeral`]s to be used with those crates;
+//! `wasm`, `ffi` and `pyo3` export the conversions to JavaScript, C and Python; and `greek` and
+//! `attic` add Greek numeral systems. With the `tracing` feature enabled, each conversion by
+//! [`integer_to_roman`], [`roman_to_integer`], [`roman_to_integer_lenient`] or
+//! [`roman_to_integer_bytes`] is recorded as a `tracing` span, with its result as a `TRACE` event
+//! and any error as a `DEBUG` event.
+
+#![cfg_attr(feature = "nightly", feature(portable_simd, step_trait))]
 
-pub use itor::integer_to_roman;
-pub use rtoi::roman_to_integer;
+use std::fmt;
 
+#[cfg(feature = "rkyv")]
+pub use archive::ArchivedRomanNumeral;
+#[cfg(feature = "clap")]
+pub use args::RomanNumeralParser;
+pub use batch::{integers_to_romans, romans_to_integers};
+#[cfg(feature = "rayon")]
+pub use batch::{par_integers_to_romans, par_romans_to_integers};
+#[cfg(feature = "cache")]
+pub use cache::RomanCache;
+pub use calendar::{auc_to_year, year_to_auc, Year};
+pub use canonical::{is_canonical, normalize};
+pub use classify::{classify, NumeralForm};
+#[cfg(feature = "nom")]
+pub use combinator::roman_numeral;
+pub use compare::{compare_numerals, sort_by_roman_value};
+pub use display::{LowerRoman, UpperRoman};
+pub use explain::{explain, explain_roman, ConversionStep};
+pub use fraction::{fraction_to_roman, roman_to_fraction, RomanFraction};
+#[cfg(feature = "arbitrary")]
+pub use fuzz::PlausibleNumeral;
+pub use iter::{IntegersToRomans, RomanIteratorExt, RomansToIntegers};
+#[cfg(feature = "precomputed")]
+pub use itor::integer_to_roman_str;
+pub use itor::{
+    decompose, integer_to_roman, integer_to_roman_unchecked, roman_len, signed_integer_to_roman,
+};
+pub use numeral::RomanNumeral;
+#[cfg(feature = "rand")]
+pub use random::UniformRomanNumeral;
+pub use range::RomanRange;
+pub use rtoi::{
+    roman_to_integer, roman_to_integer_bytes, roman_to_integer_lenient, roman_to_integer_trusted,
+    roman_to_signed_integer, tokenize,
+};
+pub use spreadsheet::{
+    integer_to_spreadsheet_roman, spreadsheet_roman_to_integer, SpreadsheetForm,
+};
+pub use stream::{Rewrite, RomanRewriter};
+pub use suggest::{roman_to_integer_with_suggestions, suggestions, ErrorWithSuggestions};
+pub use system::{numeral_system, numeral_systems, NumeralSystem, Roman};
+pub use table::{roman_table, RomanTable};
+pub use text::{
+    arabize_text, arabize_text_with, romanize_text, scan, NumeralContext, RomanMatch, RomanMatches,
+};
+pub use traits::{FromRoman, ToRoman};
+#[cfg(feature = "unicode")]
+pub use unicode::roman_to_integer_unicode;
+pub use unicode::{roman_to_integer_confusable, Substitution};
+pub use valid::ValidRoman;
+pub use words::{integer_to_latin_words, latin_words_to_integer};
+
+#[cfg(feature = "rkyv")]
+mod archive;
+#[cfg(feature = "clap")]
+mod args;
+#[cfg(feature = "attic")]
+pub mod attic;
+mod batch;
+#[cfg(feature = "cache")]
+mod cache;
+mod calendar;
+mod canonical;
+mod classify;
+#[cfg(feature = "nom")]
+mod combinator;
+mod compare;
+mod display;
+#[cfg(feature = "defmt")]
+mod embedded;
+mod explain;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fraction;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "greek")]
+pub mod greek;
+mod iter;
 mod itor;
+mod numeral;
+#[cfg(feature = "pyo3")]
+mod python;
+#[cfg(feature = "rand")]
+mod random;
+mod range;
 mod rtoi;
+mod spreadsheet;
+#[cfg(feature = "sqlx")]
+mod sql;
+mod stream;
+mod suggest;
+mod symbols;
+mod system;
+mod table;
+#[cfg(feature = "proptest")]
+pub mod testing;
+mod text;
+mod traits;
+mod unicode;
+mod valid;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(any(feature = "rocket", feature = "serde"))]
+mod web;
+mod words;
+
+/// Convenient re-exports of the extension traits, including [`RomanIteratorExt`] for iterators.
+///
+/// ```
+/// use numeris::prelude::*;
+///
+/// assert_eq!(42.to_roman().unwrap(), "XLII");
+/// ```
+pub mod prelude {
+    pub use crate::iter::RomanIteratorExt;
+    pub use crate::traits::{FromRoman, ToRoman};
+}
 
 /// The minimum value supported for Roman numerals
 pub const MIN_VALUE: u32 = 1;
@@ -24,42 +169,145 @@ pub enum RomanNumeralError {
     ValueTooLarge(u32),
     /// Indicates that the numeric value is too small to be turned into a Roman numeral.
     ValueTooSmall(u32),
-    /// Indicates a Roman numeral that could not be parsed into an integer.
+    /// Indicates a value that could not be parsed, for a reason not covered by a more specific
+    /// error.
     Unparsable(String),
+    /// Indicates a character that is not a Roman numeral symbol, found at `pos`.
+    InvalidCharacter { ch: char, pos: Position },
+    /// Indicates a symbol that is repeated more times in a row than is allowed (e.g. `IIII` or
+    /// `VV`). `count` is the number of consecutive repetitions found, and `pos` is the position of
+    /// the first repetition that is not allowed.
+    InvalidRepetition { symbol: &'static str, count: usize, pos: Position },
+    /// Indicates a symbol that is too large to appear where it does (e.g. the `M` in `CMM`), found
+    /// at `pos`. `expected_max` is the largest value that could have appeared in its place, which
+    /// is 0 if nothing could have followed the preceding symbols.
+    OutOfOrder { found: &'static str, expected_max: u32, pos: Position },
     /// Indicates an empty Roman numeral value.
     EmptyString,
 }
 
+impl fmt::Display for RomanNumeralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RomanNumeralError::ValueTooLarge(val) => {
+                write!(f, "{} is too large to be written as a Roman numeral", val)
+            }
+            RomanNumeralError::ValueTooSmall(val) => {
+                write!(f, "{} is too small to be written as a Roman numeral", val)
+            }
+            RomanNumeralError::Unparsable(val) => write!(f, "{} could not be parsed", val),
+            RomanNumeralError::InvalidCharacter { ch, pos } => {
+                write!(f, "'{}' at position {} is not a Roman numeral symbol", ch, pos.char + 1)
+            }
+            RomanNumeralError::InvalidRepetition { symbol, count, .. } => {
+                write!(f, "{} is repeated {} times in a row", symbol, count)
+            }
+            RomanNumeralError::OutOfOrder { found, expected_max, .. } => {
+                write!(f, "{} found where at most {} was expected", found, expected_max)
+            }
+            RomanNumeralError::EmptyString => f.write_str("no Roman numeral provided"),
+        }
+    }
+}
+
+impl std::error::Error for RomanNumeralError {}
+
+/// The position within the original input at which parsing failed.
+///
+/// Positions are measured from the start of the input exactly as it was given, including any
+/// leading whitespace that was trimmed before parsing.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Position {
+    /// The offset in bytes, suitable for slicing the input.
+    pub byte: usize,
+    /// The offset in characters, suitable for displaying to a user.
+    pub char: usize,
+}
+
+impl Position {
+    /// Creates a position from a byte and character offset.
+    pub fn new(byte: usize, char: usize) -> Self {
+        Position { byte, char }
+    }
+
+    /// The position immediately after `text`, which starts at this position.
+    pub(crate) fn after(self, text: &str) -> Self {
+        Position { byte: self.byte + text.len(), char: self.char + text.chars().count() }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, RomanNumeralError>;
 
-#[derive(Debug, Clone)]
-struct RomanNumeral {
+/// One of the basic building blocks of a Roman numeral: a single symbol (e.g. `X`) or a
+/// subtractive pair (e.g. `XL`), along with its value.
+///
+/// Every canonical Roman numeral can be split into a sequence of atoms with [`tokenize`], and
+/// every value can be split into the atoms of its canonical numeral with [`decompose`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RomanAtom {
     value: u32,
     symbol: &'static str,
     max_group: u8,
 }
 
-const ATOMS: [RomanNumeral; 13] = [
-    RomanNumeral { value: 1000, symbol: "M", max_group: 3 },
-    RomanNumeral { value: 900, symbol: "CM", max_group: 1 },
-    RomanNumeral { value: 500, symbol: "D", max_group: 1 },
-    RomanNumeral { value: 400, symbol: "CD", max_group: 1 },
-    RomanNumeral { value: 100, symbol: "C", max_group: 3 },
-    RomanNumeral { value: 90, symbol: "XC", max_group: 1 },
-    RomanNumeral { value: 50, symbol: "L", max_group: 1 },
-    RomanNumeral { value: 40, symbol: "XL", max_group: 1 },
-    RomanNumeral { value: 10, symbol: "X", max_group: 3 },
-    RomanNumeral { value: 9, symbol: "IX", max_group: 1 },
-    RomanNumeral { value: 5, symbol: "V", max_group: 1 },
-    RomanNumeral { value: 4, symbol: "IV", max_group: 1 },
-    RomanNumeral { value: 1, symbol: "I", max_group: 3 },
+impl RomanAtom {
+    /// The symbol, or subtractive pair of symbols, for this atom, in upper-case.
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// The value of this atom.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// The largest number of times this atom may appear in a row in a canonical numeral: 3 for
+    /// the powers of ten (`I`, `X`, `C` and `M`), and 1 for everything else.
+    pub fn max_repetitions(&self) -> u8 {
+        self.max_group
+    }
+
+    /// Whether this atom is a subtractive pair, such as `IV` or `CM`.
+    pub fn is_subtractive(&self) -> bool {
+        self.symbol.len() == 2
+    }
+}
+
+/// All of the atoms that make up canonical Roman numerals, from largest to smallest.
+///
+/// Canonical numerals are written by taking, at each step, the largest atom whose value does not
+/// exceed the value that remains, so every canonical numeral lists its atoms in this order. No
+/// atom may appear more times in a row than its [`max_repetitions`][RomanAtom::max_repetitions].
+///
+/// ```
+/// use numeris::ATOMS;
+///
+/// let symbols: Vec<&str> = ATOMS.iter().map(|atom| atom.symbol()).collect();
+/// assert_eq!(symbols, ["M", "CM", "D", "CD", "C", "XC", "L", "XL", "X", "IX", "V", "IV", "I"]);
+/// ```
+pub const ATOMS: [RomanAtom; 13] = [
+    RomanAtom { value: 1000, symbol: "M", max_group: 3 },
+    RomanAtom { value: 900, symbol: "CM", max_group: 1 },
+    RomanAtom { value: 500, symbol: "D", max_group: 1 },
+    RomanAtom { value: 400, symbol: "CD", max_group: 1 },
+    RomanAtom { value: 100, symbol: "C", max_group: 3 },
+    RomanAtom { value: 90, symbol: "XC", max_group: 1 },
+    RomanAtom { value: 50, symbol: "L", max_group: 1 },
+    RomanAtom { value: 40, symbol: "XL", max_group: 1 },
+    RomanAtom { value: 10, symbol: "X", max_group: 3 },
+    RomanAtom { value: 9, symbol: "IX", max_group: 1 },
+    RomanAtom { value: 5, symbol: "V", max_group: 1 },
+    RomanAtom { value: 4, symbol: "IV", max_group: 1 },
+    RomanAtom { value: 1, symbol: "I", max_group: 3 },
 ];
 
-pub const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg(test)]
 mod tests {
-    use super::ATOMS;
+    use super::{integer_to_roman, roman_to_integer, ATOMS};
+    #[cfg(feature = "tracing")]
+    use super::{roman_to_integer_bytes, roman_to_integer_lenient};
 
     #[test]
     fn check_atoms() {
@@ -67,4 +315,65 @@ mod tests {
         assert_eq!(40, ATOMS[7].value);
         assert_eq!(1, ATOMS[12].value);
     }
+
+    #[test]
+    fn atoms_are_ordered_by_value() {
+        assert!(ATOMS.windows(2).all(|pair| pair[0].value() > pair[1].value()));
+    }
+
+    #[test]
+    fn describe_errors() {
+        let describe = |e: crate::RomanNumeralError| e.to_string();
+        assert_eq!(
+            describe(integer_to_roman(4000).unwrap_err()),
+            "4000 is too large to be written as a Roman numeral"
+        );
+        assert_eq!(
+            describe(integer_to_roman(0).unwrap_err()),
+            "0 is too small to be written as a Roman numeral"
+        );
+        assert_eq!(
+            describe(roman_to_integer(" XIVé").unwrap_err()),
+            "'é' at position 5 is not a Roman numeral symbol"
+        );
+        assert_eq!(
+            describe(roman_to_integer("XIIII").unwrap_err()),
+            "I is repeated 4 times in a row"
+        );
+        assert_eq!(
+            describe(roman_to_integer("CMM").unwrap_err()),
+            "M found where at most 90 was expected"
+        );
+        assert_eq!(describe(roman_to_integer("").unwrap_err()), "no Roman numeral provided");
+    }
+
+    #[test]
+    fn check_atom_rules() {
+        for atom in ATOMS.iter() {
+            let is_power_of_ten = ["M", "C", "X", "I"].contains(&atom.symbol());
+            assert_eq!(atom.max_repetitions(), if is_power_of_ten { 3 } else { 1 });
+        }
+        assert!(ATOMS[1].is_subtractive());
+        assert!(!ATOMS[2].is_subtractive());
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    #[tracing_test::traced_test]
+    fn trace_conversions() {
+        integer_to_roman(1142).unwrap();
+        roman_to_integer("XIIII").unwrap_err();
+        roman_to_integer_lenient("xiiii").unwrap();
+        roman_to_integer_bytes(b" XIV").unwrap();
+        integer_to_roman(4000).unwrap_err();
+        assert!(logs_contain("u32_to_roman{value=1142 style="));
+        assert!(logs_contain("return=\"MCXLII\""));
+        assert!(logs_contain("roman_to_integer{input_len=5 style=\"strict\"}"));
+        assert!(logs_contain("error=InvalidRepetition { symbol: \"I\", count: 4"));
+        assert!(logs_contain("roman_to_integer_lenient{input_len=5 style=\"lenient\"}"));
+        assert!(logs_contain(
+            "roman_to_integer_bytes{input_len=4 style=\"bytes\"}: numeris::rtoi: return=14"
+        ));
+        assert!(logs_contain("error=ValueTooLarge(4000)"));
+    }
 }
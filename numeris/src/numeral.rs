@@ -0,0 +1,472 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Write};
+use std::iter::{Product, Sum};
+use std::num::{NonZeroU32, NonZeroU8};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use super::{
+    integer_to_roman, roman_to_integer, Result, RomanNumeralError, RomanRange, MAX_VALUE, MIN_VALUE,
+};
+
+/// A valid Roman numeral, stored as its value.
+///
+/// A `RomanNumeral` can only be created for values between [`MIN_VALUE`] and [`MAX_VALUE`], so
+/// it can always be displayed without any possibility of failure. Numerals can be compared
+/// directly with `u32` values.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::RomanNumeral;
+///
+/// let rn = RomanNumeral::new(1142).unwrap();
+/// assert_eq!(rn.to_string(), "MCXLII");
+/// let rn: RomanNumeral = "xlii".parse().unwrap();
+/// assert_eq!(rn.value(), 42);
+/// assert!(rn == 42 && rn < 100);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RomanNumeral(u32);
+
+impl RomanNumeral {
+    /// The smallest numeral, `I`, with the value [`MIN_VALUE`].
+    pub const MIN: RomanNumeral = RomanNumeral(MIN_VALUE);
+
+    /// The largest numeral, `MMMCMXCIX`, with the value [`MAX_VALUE`].
+    pub const MAX: RomanNumeral = RomanNumeral(MAX_VALUE);
+
+    /// The values that can be represented as Roman numerals, from [`MIN_VALUE`] to [`MAX_VALUE`].
+    ///
+    /// ```
+    /// use numeris::RomanNumeral;
+    ///
+    /// let thousands = RomanNumeral::VALID_RANGE.filter(|val| val % 1000 == 0);
+    /// assert_eq!(thousands.count(), 3);
+    /// ```
+    pub const VALID_RANGE: RangeInclusive<u32> = MIN_VALUE..=MAX_VALUE;
+
+    /// Whether `val` can be represented as a Roman numeral, i.e. whether it is within
+    /// [`VALID_RANGE`][RomanNumeral::VALID_RANGE].
+    ///
+    /// ```
+    /// use numeris::RomanNumeral;
+    ///
+    /// assert!(RomanNumeral::in_range(3999));
+    /// assert!(!RomanNumeral::in_range(0));
+    /// ```
+    pub const fn in_range(val: u32) -> bool {
+        val >= MIN_VALUE && val <= MAX_VALUE
+    }
+
+    /// Creates a Roman numeral with the given value.
+    ///
+    /// # Errors
+    ///
+    /// | `RomanNumeralErrorKind` | Reason |
+    /// | ----------------------- | ------ |
+    /// | [`ValueTooSmall`][a] | `val` is too small to be represented as a Roman numeral |
+    /// | [`ValueTooLarge`][b] |  `val` is too large to be represented as a Roman numeral |
+    ///
+    /// [a]: crate::RomanNumeralError::ValueTooSmall
+    /// [b]: crate::RomanNumeralError::ValueTooLarge
+    pub fn new(val: u32) -> Result<Self> {
+        if val < MIN_VALUE {
+            Err(RomanNumeralError::ValueTooSmall(val))
+        } else if val > MAX_VALUE {
+            Err(RomanNumeralError::ValueTooLarge(val))
+        } else {
+            Ok(RomanNumeral(val))
+        }
+    }
+
+    /// The value of this numeral.
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    /// The value of this numeral, which is never zero.
+    ///
+    /// ```
+    /// use numeris::RomanNumeral;
+    ///
+    /// let rn: RomanNumeral = "XLII".parse().unwrap();
+    /// assert_eq!(rn.to_nonzero().get(), 42);
+    /// ```
+    pub fn to_nonzero(&self) -> NonZeroU32 {
+        NonZeroU32::new(self.0).expect("numerals are never zero")
+    }
+
+    /// The next numeral, or `None` if this is the largest numeral ([`MAX_VALUE`]).
+    ///
+    /// ```
+    /// use numeris::RomanNumeral;
+    ///
+    /// let rn: RomanNumeral = "XIX".parse().unwrap();
+    /// assert_eq!(rn.succ().unwrap().to_string(), "XX");
+    /// assert_eq!(RomanNumeral::MAX.succ(), None);
+    /// ```
+    pub fn succ(&self) -> Option<Self> {
+        RomanNumeral::new(self.0 + 1).ok()
+    }
+
+    /// The previous numeral, or `None` if this is the smallest numeral ([`MIN_VALUE`]).
+    ///
+    /// ```
+    /// use numeris::RomanNumeral;
+    ///
+    /// let rn: RomanNumeral = "XX".parse().unwrap();
+    /// assert_eq!(rn.pred().unwrap().to_string(), "XIX");
+    /// assert_eq!(RomanNumeral::MIN.pred(), None);
+    /// ```
+    pub fn pred(&self) -> Option<Self> {
+        RomanNumeral::new(self.0 - 1).ok()
+    }
+
+    /// Iterates over the numerals from this one up to `end`, inclusive.
+    ///
+    /// This is the stable equivalent of `start..=end`, which is only available with the `nightly`
+    /// feature.
+    ///
+    /// ```
+    /// use numeris::RomanNumeral;
+    ///
+    /// let start: RomanNumeral = "I".parse().unwrap();
+    /// let end: RomanNumeral = "V".parse().unwrap();
+    /// let numerals: Vec<String> = start.iter_to(end).map(|rn| rn.to_string()).collect();
+    /// assert_eq!(numerals, ["I", "II", "III", "IV", "V"]);
+    /// ```
+    pub fn iter_to(self, end: RomanNumeral) -> RomanRange {
+        RomanRange::between(self, end)
+    }
+}
+
+/// Writes the numeral in its canonical form.
+///
+/// As with integers, the width, fill and alignment of the format are honoured, and numerals are
+/// aligned to the right unless another alignment is given. The precision is ignored.
+///
+/// ```
+/// use numeris::RomanNumeral;
+///
+/// let rn = RomanNumeral::new(42).unwrap();
+/// assert_eq!(format!("[{:6}]", rn), "[  XLII]");
+/// assert_eq!(format!("[{:<6}]", rn), "[XLII  ]");
+/// assert_eq!(format!("[{:*^8}]", rn), "[**XLII**]");
+/// ```
+impl fmt::Display for RomanNumeral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numeral = integer_to_roman(self.0).map_err(|_| fmt::Error)?;
+        pad_numeral(f, &numeral)
+    }
+}
+
+/// Writes `numeral` padded to the width of `f`, with its fill and alignment, defaulting to the
+/// right as for integers. Unlike [`fmt::Formatter::pad`], the numeral is never truncated to the
+/// precision of `f`, as that would change its value.
+pub(crate) fn pad_numeral(f: &mut fmt::Formatter<'_>, numeral: &str) -> fmt::Result {
+    let padding = f.width().map_or(0, |width| width.saturating_sub(numeral.chars().count()));
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(numeral)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl FromStr for RomanNumeral {
+    type Err = RomanNumeralError;
+
+    /// Parses a Roman numeral, as accepted by [`roman_to_integer`].
+    fn from_str(s: &str) -> Result<Self> {
+        roman_to_integer(s).map(RomanNumeral)
+    }
+}
+
+impl TryFrom<u32> for RomanNumeral {
+    type Error = RomanNumeralError;
+
+    fn try_from(val: u32) -> Result<Self> {
+        RomanNumeral::new(val)
+    }
+}
+
+/// Creates a Roman numeral from a value that is known not to be zero.
+///
+/// The only possible error is [`ValueTooLarge`][a], when `val` is greater than [`MAX_VALUE`].
+///
+/// [a]: crate::RomanNumeralError::ValueTooLarge
+impl TryFrom<NonZeroU32> for RomanNumeral {
+    type Error = RomanNumeralError;
+
+    fn try_from(val: NonZeroU32) -> Result<Self> {
+        RomanNumeral::new(val.get())
+    }
+}
+
+/// Creates a Roman numeral from a value that is known not to be zero, which cannot fail, as every
+/// `NonZeroU8` is in range.
+///
+/// ```
+/// use std::num::NonZeroU8;
+///
+/// use numeris::RomanNumeral;
+///
+/// let rn = RomanNumeral::from(NonZeroU8::new(42).unwrap());
+/// assert_eq!(rn.to_string(), "XLII");
+/// ```
+impl From<NonZeroU8> for RomanNumeral {
+    fn from(val: NonZeroU8) -> Self {
+        RomanNumeral(u32::from(val.get()))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl std::iter::Step for RomanNumeral {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        match end.0.checked_sub(start.0) {
+            Some(steps) => (steps as usize, Some(steps as usize)),
+            None => (0, None),
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        let count = u32::try_from(count).ok()?;
+        RomanNumeral::new(start.0.checked_add(count)?).ok()
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        let count = u32::try_from(count).ok()?;
+        RomanNumeral::new(start.0.checked_sub(count)?).ok()
+    }
+}
+
+impl From<RomanNumeral> for u32 {
+    fn from(numeral: RomanNumeral) -> Self {
+        numeral.0
+    }
+}
+
+impl From<RomanNumeral> for NonZeroU32 {
+    fn from(numeral: RomanNumeral) -> Self {
+        numeral.to_nonzero()
+    }
+}
+
+impl PartialEq<u32> for RomanNumeral {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<RomanNumeral> for u32 {
+    fn eq(&self, other: &RomanNumeral) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialOrd<u32> for RomanNumeral {
+    fn partial_cmp(&self, other: &u32) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<RomanNumeral> for u32 {
+    fn partial_cmp(&self, other: &RomanNumeral) -> Option<Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+/// Totals an iterator of numerals.
+///
+/// The result is [`ValueTooLarge`][a] if the total exceeds [`MAX_VALUE`], and
+/// [`ValueTooSmall`][b] if the iterator is empty, as there is no numeral for zero.
+///
+/// ```
+/// use numeris::{Result, RomanNumeral};
+///
+/// let numerals = ["XIV", "XXVIII"].iter().map(|rn| rn.parse::<RomanNumeral>().unwrap());
+/// let total: Result<RomanNumeral> = numerals.sum();
+/// assert_eq!(total.unwrap().to_string(), "XLII");
+/// ```
+///
+/// [a]: crate::RomanNumeralError::ValueTooLarge
+/// [b]: crate::RomanNumeralError::ValueTooSmall
+impl Sum<RomanNumeral> for Result<RomanNumeral> {
+    fn sum<I: Iterator<Item = RomanNumeral>>(iter: I) -> Self {
+        RomanNumeral::new(iter.fold(0, |total, rn| total.saturating_add(rn.0)))
+    }
+}
+
+impl<'a> Sum<&'a RomanNumeral> for Result<RomanNumeral> {
+    fn sum<I: Iterator<Item = &'a RomanNumeral>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies together an iterator of numerals.
+///
+/// The result is [`ValueTooLarge`][a] if the product exceeds [`MAX_VALUE`]. The product of an
+/// empty iterator is `I`.
+///
+/// ```
+/// use numeris::{Result, RomanNumeral};
+///
+/// let numerals = ["VI", "VII"].iter().map(|rn| rn.parse::<RomanNumeral>().unwrap());
+/// let product: Result<RomanNumeral> = numerals.product();
+/// assert_eq!(product.unwrap().to_string(), "XLII");
+/// ```
+///
+/// [a]: crate::RomanNumeralError::ValueTooLarge
+impl Product<RomanNumeral> for Result<RomanNumeral> {
+    fn product<I: Iterator<Item = RomanNumeral>>(iter: I) -> Self {
+        RomanNumeral::new(iter.fold(1, |total, rn| total.saturating_mul(rn.0)))
+    }
+}
+
+impl<'a> Product<&'a RomanNumeral> for Result<RomanNumeral> {
+    fn product<I: Iterator<Item = &'a RomanNumeral>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::num::{NonZeroU32, NonZeroU8};
+
+    use crate::{Result, RomanNumeral, RomanNumeralError, MAX_VALUE, MIN_VALUE};
+
+    fn numerals(values: &[u32]) -> Vec<RomanNumeral> {
+        values.iter().map(|val| RomanNumeral::new(*val).unwrap()).collect()
+    }
+
+    #[test]
+    fn create_numerals_in_range() {
+        assert_eq!(RomanNumeral::new(MIN_VALUE).unwrap().value(), MIN_VALUE);
+        assert_eq!(RomanNumeral::new(MAX_VALUE).unwrap().value(), MAX_VALUE);
+        assert_eq!(RomanNumeral::new(0), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(RomanNumeral::try_from(4000), Err(RomanNumeralError::ValueTooLarge(4000)));
+    }
+
+    #[test]
+    fn check_limits() {
+        assert_eq!(RomanNumeral::MIN.to_string(), "I");
+        assert_eq!(RomanNumeral::MAX.to_string(), "MMMCMXCIX");
+        assert_eq!(RomanNumeral::VALID_RANGE, MIN_VALUE..=MAX_VALUE);
+        for val in 0..=MAX_VALUE + 1 {
+            assert_eq!(RomanNumeral::in_range(val), RomanNumeral::new(val).is_ok(), "{}", val);
+        }
+    }
+
+    #[test]
+    fn convert_nonzero_values() {
+        let val = NonZeroU32::new(1142).unwrap();
+        let rn = RomanNumeral::try_from(val).unwrap();
+        assert_eq!((rn.to_nonzero(), NonZeroU32::from(rn)), (val, val));
+        let val = NonZeroU32::new(4000).unwrap();
+        assert_eq!(RomanNumeral::try_from(val), Err(RomanNumeralError::ValueTooLarge(4000)));
+        for val in 1..=u8::MAX {
+            let rn = RomanNumeral::from(NonZeroU8::new(val).unwrap());
+            assert_eq!(rn.value(), u32::from(val));
+        }
+    }
+
+    #[test]
+    fn display_and_parse_numerals() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let rn = RomanNumeral::new(val).unwrap();
+            assert_eq!(rn.to_string().parse(), Ok(rn));
+            assert_eq!(u32::from(rn), val);
+        }
+        assert_eq!(" iv ".parse::<RomanNumeral>().unwrap().to_string(), "IV");
+        assert_eq!("".parse::<RomanNumeral>(), Err(RomanNumeralError::EmptyString));
+    }
+
+    #[test]
+    fn pad_displayed_numerals() {
+        let rn = RomanNumeral::new(1142).unwrap();
+        assert_eq!(format!("{}", rn), "MCXLII");
+        assert_eq!(format!("{:4}", rn), "MCXLII");
+        assert_eq!(format!("{:>8}|{:<8}|", rn, rn), "  MCXLII|MCXLII  |");
+        assert_eq!(format!("{:^9}|{:-^10}", rn, rn), " MCXLII  |--MCXLII--");
+        assert_eq!(format!("{:8.2}", rn), "  MCXLII");
+    }
+
+    #[test]
+    fn step_to_adjacent_numerals() {
+        let rn = RomanNumeral::new(40).unwrap();
+        assert_eq!(rn.succ().unwrap().to_string(), "XLI");
+        assert_eq!(rn.pred().unwrap().to_string(), "XXXIX");
+        assert_eq!(rn.succ().unwrap().pred(), Some(rn));
+        assert_eq!(RomanNumeral::new(MAX_VALUE).unwrap().succ(), None);
+        assert_eq!(RomanNumeral::new(MIN_VALUE).unwrap().pred(), None);
+    }
+
+    #[test]
+    fn iterate_to_end() {
+        let start = RomanNumeral::new(98).unwrap();
+        let numerals: Vec<String> =
+            start.iter_to(RomanNumeral::new(101).unwrap()).map(|rn| rn.to_string()).collect();
+        assert_eq!(numerals, ["XCVIII", "XCIX", "C", "CI"]);
+        assert_eq!(start.iter_to(RomanNumeral::new(97).unwrap()).count(), 0);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn iterate_with_range_syntax() {
+        let start: RomanNumeral = "I".parse().unwrap();
+        let end: RomanNumeral = "X".parse().unwrap();
+        assert_eq!((start..=end).count(), 10);
+        assert_eq!((start..end).next_back().unwrap().to_string(), "IX");
+        let max = RomanNumeral::new(MAX_VALUE).unwrap();
+        assert_eq!((max..=max).count(), 1);
+    }
+
+    #[test]
+    fn sum_numerals() {
+        let total: Result<RomanNumeral> = numerals(&[1000, 100, 40, 2]).into_iter().sum();
+        assert_eq!(total.unwrap().to_string(), "MCXLII");
+        let total: Result<RomanNumeral> = numerals(&[3999, 1]).iter().sum();
+        assert_eq!(total, Err(RomanNumeralError::ValueTooLarge(4000)));
+        let total: Result<RomanNumeral> = numerals(&[]).into_iter().sum();
+        assert_eq!(total, Err(RomanNumeralError::ValueTooSmall(0)));
+    }
+
+    #[test]
+    fn multiply_numerals() {
+        let product: Result<RomanNumeral> = numerals(&[2, 3, 7]).iter().product();
+        assert_eq!(product.unwrap().to_string(), "XLII");
+        let product: Result<RomanNumeral> = numerals(&[]).into_iter().product();
+        assert_eq!(product.unwrap().to_string(), "I");
+        let product: Result<RomanNumeral> = numerals(&[3999; 4]).into_iter().product();
+        assert_eq!(product, Err(RomanNumeralError::ValueTooLarge(u32::MAX)));
+    }
+
+    #[test]
+    fn numerals_are_ordered_by_value() {
+        let small = RomanNumeral::new(9).unwrap();
+        let large = RomanNumeral::new(10).unwrap();
+        assert!(small < large);
+    }
+
+    #[test]
+    fn compare_numerals_with_integers() {
+        let rn: RomanNumeral = "XLII".parse().unwrap();
+        assert!(rn == 42);
+        assert!(42 == rn);
+        assert!(rn != 41);
+        assert!(rn < 100 && rn > 41 && rn <= 42 && rn >= 42);
+        assert!(100 > rn && 41 < rn);
+        assert!(0 < rn && rn < 4000);
+    }
+}
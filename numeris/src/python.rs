@@ -0,0 +1,152 @@
+//! Python bindings, built with `maturin`. Requires the `pyo3` feature.
+//!
+//! `maturin` builds the crate as a `cdylib` itself, with the features listed in `pyproject.toml`,
+//! so `maturin build` or `maturin develop` is all that is needed.
+//!
+//! The Python module is named `numeris`, and any error is raised as a `ValueError` carrying the
+//! error's message:
+//!
+//! ```python
+//! import numeris
+//!
+//! numeris.integer_to_roman(1142)  # 'MCXLII'
+//! numeris.roman_to_integer("xlii")  # 42
+//! rn = numeris.RomanNumeral("XLII")
+//! int(rn), str(rn)  # (42, 'XLII')
+//! numeris.roman_to_integer("XIIII")  # ValueError: I is repeated 4 times in a row
+//! ```
+
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use super::{RomanNumeral, RomanNumeralError};
+
+impl From<RomanNumeralError> for PyErr {
+    fn from(error: RomanNumeralError) -> Self {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+/// Converts an integer into a string representing a Roman numeral.
+#[pyfunction]
+fn integer_to_roman(val: i64) -> PyResult<String> {
+    Ok(crate::integer_to_roman(val)?)
+}
+
+/// Converts a string representing a Roman numeral into an integer.
+#[pyfunction]
+fn roman_to_integer(numeral: &str) -> PyResult<u32> {
+    Ok(crate::roman_to_integer(numeral)?)
+}
+
+/// A valid Roman numeral, created from either its value or a string.
+#[pyclass(name = "RomanNumeral", module = "numeris", frozen)]
+#[derive(Clone, Copy)]
+struct PyRomanNumeral(RomanNumeral);
+
+#[pymethods]
+impl PyRomanNumeral {
+    #[new]
+    fn new(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let numeral = match value.extract::<&str>() {
+            Ok(numeral) => numeral.parse()?,
+            Err(_) => RomanNumeral::new(crate::itor::to_u32(value.extract::<i64>()?)?)?,
+        };
+        Ok(PyRomanNumeral(numeral))
+    }
+
+    /// The value of this numeral.
+    #[getter]
+    fn value(&self) -> u32 {
+        self.0.value()
+    }
+
+    fn __int__(&self) -> u32 {
+        self.0.value()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("RomanNumeral('{}')", self.0)
+    }
+
+    fn __hash__(&self) -> u64 {
+        u64::from(self.0.value())
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.0.cmp(&other.0))
+    }
+}
+
+#[pymodule]
+fn numeris(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(integer_to_roman, m)?)?;
+    m.add_function(wrap_pyfunction!(roman_to_integer, m)?)?;
+    m.add_class::<PyRomanNumeral>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::exceptions::PyValueError;
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+
+    fn run(code: &str) -> PyResult<()> {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "numeris")?;
+            super::numeris(&module)?;
+            let globals = PyDict::new(py);
+            globals.set_item("numeris", module)?;
+            py.run(&std::ffi::CString::new(code).unwrap(), Some(&globals), None)
+        })
+    }
+
+    fn error_message(code: &str) -> String {
+        let error = run(code).unwrap_err();
+        Python::with_gil(|py| {
+            assert!(error.is_instance_of::<PyValueError>(py));
+            error.value(py).to_string()
+        })
+    }
+
+    #[test]
+    fn convert_from_python() {
+        run("assert numeris.integer_to_roman(1142) == 'MCXLII'").unwrap();
+        run("assert numeris.roman_to_integer(' xlii ') == 42").unwrap();
+    }
+
+    #[test]
+    fn use_numerals_from_python() {
+        let code = "
+rn = numeris.RomanNumeral('xlii')
+assert (rn.value, int(rn), str(rn), repr(rn)) == (42, 42, 'XLII', \"RomanNumeral('XLII')\")
+assert rn == numeris.RomanNumeral(42) and rn < numeris.RomanNumeral(43)
+assert len({rn, numeris.RomanNumeral('XLII')}) == 1
+";
+        run(code).unwrap();
+    }
+
+    #[test]
+    fn raise_value_errors() {
+        assert_eq!(
+            error_message("numeris.integer_to_roman(-5)"),
+            "0 is too small to be written as a Roman numeral"
+        );
+        assert_eq!(
+            error_message("numeris.roman_to_integer('XIIII')"),
+            "I is repeated 4 times in a row"
+        );
+        assert_eq!(error_message("numeris.RomanNumeral('')"), "no Roman numeral provided");
+        assert_eq!(
+            error_message("numeris.RomanNumeral(4000)"),
+            "4000 is too large to be written as a Roman numeral"
+        );
+    }
+}
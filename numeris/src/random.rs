@@ -0,0 +1,118 @@
+//! Generating random numerals with `rand`. Requires the `rand` feature.
+//!
+//! Random numerals can be generated from every valid value, as by `rand::random()`, or from a
+//! range of numerals, e.g. `rng.gen_range(start..=end)`.
+
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use super::{RomanNumeral, MAX_VALUE, MIN_VALUE};
+
+/// Generates numerals uniformly from every valid value, `I` to `MMMCMXCIX`.
+///
+/// ```
+/// use numeris::RomanNumeral;
+///
+/// let rn: RomanNumeral = rand::random();
+/// assert!(rn >= 1 && rn <= 3999);
+/// ```
+impl Distribution<RomanNumeral> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RomanNumeral {
+        RomanNumeral::new(rng.gen_range(MIN_VALUE..=MAX_VALUE)).expect("sampled a valid value")
+    }
+}
+
+/// Samples numerals uniformly from a range of numerals.
+///
+/// This is the [`UniformSampler`] for [`RomanNumeral`], which allows numerals to be generated
+/// from a range with [`Rng::gen_range`], or with a reusable
+/// [`Uniform`][rand::distributions::Uniform] distribution. Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::RomanNumeral;
+/// use rand::Rng;
+///
+/// let start: RomanNumeral = "X".parse().unwrap();
+/// let end: RomanNumeral = "XX".parse().unwrap();
+/// let rn = rand::thread_rng().gen_range(start..=end);
+/// assert!(rn >= 10 && rn <= 20);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UniformRomanNumeral(UniformInt<u32>);
+
+impl UniformSampler for UniformRomanNumeral {
+    type X = RomanNumeral;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformRomanNumeral(UniformInt::new(low.borrow().value(), high.borrow().value()))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformRomanNumeral(UniformInt::new_inclusive(low.borrow().value(), high.borrow().value()))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        RomanNumeral::new(self.0.sample(rng)).expect("sampled between two valid values")
+    }
+}
+
+impl SampleUniform for RomanNumeral {
+    type Sampler = UniformRomanNumeral;
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::distributions::{Standard, Uniform};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::{RomanNumeral, MAX_VALUE, MIN_VALUE};
+
+    fn rn(val: u32) -> RomanNumeral {
+        RomanNumeral::new(val).unwrap()
+    }
+
+    #[test]
+    fn sample_every_value() {
+        let rng = StdRng::seed_from_u64(1142);
+        let mut seen = vec![false; MAX_VALUE as usize + 1];
+        for rn in rng.sample_iter::<RomanNumeral, _>(Standard).take(100_000) {
+            seen[rn.value() as usize] = true;
+        }
+        assert!(!seen[0]);
+        assert!(seen[MIN_VALUE as usize..].iter().all(|&seen| seen));
+    }
+
+    #[test]
+    fn sample_from_ranges() {
+        let mut rng = StdRng::seed_from_u64(1142);
+        for _ in 0..1000 {
+            assert!((10..20).contains(&rng.gen_range(rn(10)..rn(20)).value()));
+            assert!((10..=20).contains(&rng.gen_range(rn(10)..=rn(20)).value()));
+        }
+        assert_eq!(rng.gen_range(rn(42)..=rn(42)), rn(42));
+        let values: Vec<u32> = rng
+            .sample_iter(Uniform::new_inclusive(rn(1), rn(3)))
+            .take(100)
+            .map(|rn| rn.value())
+            .collect();
+        assert!((1..=3).all(|val| values.contains(&val)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn reject_empty_ranges() {
+        StdRng::seed_from_u64(1142).gen_range(rn(20)..rn(10));
+    }
+}
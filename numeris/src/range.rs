@@ -0,0 +1,121 @@
+use std::iter::FusedIterator;
+
+use super::{Result, RomanNumeral};
+
+/// An iterator over an inclusive range of Roman numerals, in order of value.
+///
+/// Numerals are produced lazily, and the range can be iterated from either end. If `start` is
+/// greater than `end`, the range is empty.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::RomanRange;
+///
+/// let labels: Vec<String> = RomanRange::new(1, 4).unwrap().map(|rn| rn.to_string()).collect();
+/// assert_eq!(labels, ["I", "II", "III", "IV"]);
+/// assert_eq!(RomanRange::new(1, 100).unwrap().rev().next().unwrap().to_string(), "C");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RomanRange {
+    front: u32,
+    back: u32,
+}
+
+impl RomanRange {
+    /// Creates a range of the Roman numerals from `start` to `end`, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by [`RomanNumeral::new`] for `start` or `end`.
+    pub fn new(start: u32, end: u32) -> Result<Self> {
+        let front = RomanNumeral::new(start)?.value();
+        let back = RomanNumeral::new(end)?.value();
+        Ok(RomanRange { front, back })
+    }
+
+    /// Creates a range of the Roman numerals from `start` to `end`, inclusive.
+    pub(crate) fn between(start: RomanNumeral, end: RomanNumeral) -> Self {
+        RomanRange { front: start.value(), back: end.value() }
+    }
+}
+
+impl Iterator for RomanRange {
+    type Item = RomanNumeral;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front > self.back {
+            return None;
+        }
+        let numeral = RomanNumeral::new(self.front).ok();
+        self.front += 1;
+        numeral
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back + 1).saturating_sub(self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for RomanRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front > self.back {
+            return None;
+        }
+        let numeral = RomanNumeral::new(self.back).ok();
+        self.back -= 1;
+        numeral
+    }
+}
+
+impl ExactSizeIterator for RomanRange {}
+
+impl FusedIterator for RomanRange {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RomanNumeralError, RomanRange, MAX_VALUE, MIN_VALUE};
+
+    #[test]
+    fn iterate_forwards_and_backwards() {
+        let numerals: Vec<String> =
+            RomanRange::new(8, 11).unwrap().map(|rn| rn.to_string()).collect();
+        assert_eq!(numerals, ["VIII", "IX", "X", "XI"]);
+        let numerals: Vec<u32> =
+            RomanRange::new(8, 11).unwrap().rev().map(|rn| rn.value()).collect();
+        assert_eq!(numerals, [11, 10, 9, 8]);
+    }
+
+    #[test]
+    fn iterate_from_both_ends() {
+        let mut range = RomanRange::new(1, 3).unwrap();
+        assert_eq!(range.len(), 3);
+        assert_eq!(range.next().unwrap().value(), 1);
+        assert_eq!(range.next_back().unwrap().value(), 3);
+        assert_eq!(range.next().unwrap().value(), 2);
+        assert_eq!(range.len(), 0);
+        assert!(range.next().is_none());
+        assert!(range.next_back().is_none());
+    }
+
+    #[test]
+    fn iterate_full_range() {
+        let range = RomanRange::new(MIN_VALUE, MAX_VALUE).unwrap();
+        assert_eq!(range.len(), 3999);
+        assert_eq!(range.map(|rn| rn.value()).sum::<u32>(), 3999 * 4000 / 2);
+        assert_eq!(RomanRange::new(MIN_VALUE, MIN_VALUE).unwrap().rev().count(), 1);
+    }
+
+    #[test]
+    fn empty_ranges() {
+        assert_eq!(RomanRange::new(10, 9).unwrap().count(), 0);
+        assert_eq!(RomanRange::new(10, 1).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn reject_ranges_out_of_bounds() {
+        assert_eq!(RomanRange::new(0, 10), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(RomanRange::new(1, 4000), Err(RomanNumeralError::ValueTooLarge(4000)));
+    }
+}
@@ -1,8 +1,5 @@
-use itertools::fold;
-use lazy_static::lazy_static;
-use regex::Regex;
-
-use super::{ATOMS, Result, RomanNumeral, RomanNumeralError};
+use super::symbols::all_symbols;
+use super::{Position, Result, RomanAtom, RomanNumeralError, ATOMS, MAX_VALUE, MIN_VALUE};
 
 /// Converts a string representing a Roman numeral into an integer.
 ///
@@ -26,12 +23,28 @@ use super::{ATOMS, Result, RomanNumeral, RomanNumeralError};
 /// use numeris::{roman_to_integer, RomanNumeralError};
 ///
 /// match roman_to_integer("BAD") {
-///     Err(RomanNumeralError::Unparsable(_)) => println!("BAD input"),
+///     Err(RomanNumeralError::InvalidCharacter { ch: 'B', .. }) => println!("BAD input"),
 ///     Err(_) => panic!("wrong kind of BAD"),
 ///     Ok(_) => panic!("BAD is not good"),
 /// };
 /// ```
 ///
+/// ### Invalid sequences
+/// ```
+/// use numeris::{roman_to_integer, Position, RomanNumeralError};
+///
+/// let pos = Position::new(4, 4);
+/// assert_eq!(
+///     roman_to_integer("XIIII"),
+///     Err(RomanNumeralError::InvalidRepetition { symbol: "I", count: 4, pos })
+/// );
+/// // Positions are relative to the untrimmed input
+/// assert_eq!(
+///     roman_to_integer("  XCX"),
+///     Err(RomanNumeralError::OutOfOrder { found: "X", expected_max: 9, pos })
+/// );
+/// ```
+///
 /// ### Empty input
 /// ```
 /// use numeris::{roman_to_integer, RomanNumeralError};
@@ -47,125 +60,535 @@ use super::{ATOMS, Result, RomanNumeral, RomanNumeralError};
 ///
 /// | `RomanNumeralError` | Reason |
 /// | ----------------------- | ------ |
-/// | [`Unparsable`][a] | `numeral` cannot be parsed as a Roman numeral |
-/// | [`EmptyString`][b] |  `numeral` is an empty string or contains only whitespace |
+/// | [`InvalidCharacter`][a] | `numeral` contains a character that is not a Roman numeral symbol |
+/// | [`InvalidRepetition`][b] | `numeral` repeats a symbol too many times in a row |
+/// | [`OutOfOrder`][c] | `numeral` contains a symbol that is too large for its position |
+/// | [`EmptyString`][d] |  `numeral` is an empty string or contains only whitespace |
 ///
-/// [a]: crate::RomanNumeralError::Unparsable
-/// [b]: crate::RomanNumeralError::EmptyString
+/// [a]: crate::RomanNumeralError::InvalidCharacter
+/// [b]: crate::RomanNumeralError::InvalidRepetition
+/// [c]: crate::RomanNumeralError::OutOfOrder
+/// [d]: crate::RomanNumeralError::EmptyString
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "trace",
+        skip_all,
+        fields(input_len = numeral.len(), style = "strict"),
+        ret(level = "trace"),
+        err(level = "debug", Debug)
+    )
+)]
 pub fn roman_to_integer(numeral: &str) -> Result<u32> {
-    let numeral = normalize_numeral(&numeral);
-    let numeral = check_numeral_format(&numeral)?;
-    let digits: Vec<u32> = decompose_numeral(numeral.as_str())?;
-    let result = fold(digits.as_slice(), 0, |seed, &val| seed + val);
-    Ok(result)
+    roman_to_integer_at(numeral, Position::default())
 }
 
-fn normalize_numeral(numeral: &str) -> String {
-    numeral.trim().to_ascii_uppercase()
+/// Converts a string representing a Roman numeral into an integer, without validating it.
+///
+/// This is a faster alternative to [`roman_to_integer`] for numerals that the caller has already
+/// validated, for example because they were produced by
+/// [`integer_to_roman`][crate::integer_to_roman]. Case is ignored. Invalid input does not cause
+/// undefined behaviour or panics, but the result is meaningless: characters that are not Roman
+/// numeral symbols count as zero, each symbol followed by a larger one is subtracted, and the
+/// total wraps around on overflow.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::roman_to_integer_trusted;
+///
+/// assert_eq!(roman_to_integer_trusted("MCXLII"), 1142);
+/// assert_eq!(roman_to_integer_trusted("mcxlii"), 1142);
+/// ```
+pub fn roman_to_integer_trusted(numeral: &str) -> u32 {
+    let symbols = numeral.as_bytes();
+    let mut total = 0u32;
+    for (pos, &symbol) in symbols.iter().enumerate() {
+        let value = symbol_value(symbol);
+        let next = symbols.get(pos + 1).map_or(0, |&b| symbol_value(b));
+        total = if next > value { total.wrapping_sub(value) } else { total.wrapping_add(value) };
+    }
+    total
 }
 
-fn check_numeral_format(numeral: &String) -> Result<&String> {
-    lazy_static! {
-        static ref RE: Regex = Regex::new(r"^[IVXLCDM]+$").unwrap();
-    }
-    if numeral.len() == 0 {
-        Err(RomanNumeralError::EmptyString)
-    } else if !RE.is_match(numeral) {
-        Err(RomanNumeralError::Unparsable(numeral.clone()))
+/// Parses `numeral`, which starts at `start` within some larger input.
+pub(crate) fn roman_to_integer_at(numeral: &str, start: Position) -> Result<u32> {
+    let (numeral, start) = validate_numeral(numeral, start)?;
+    ParseState::new(numeral.as_bytes(), start).map(|rn| rn.map(|rn| rn.value)).sum()
+}
+
+/// Converts a byte string representing a Roman numeral into an integer.
+///
+/// This behaves exactly like [`roman_to_integer`], but takes the numeral as bytes, e.g. as read
+/// from a network protocol. Valid numerals are parsed without first checking that the input is
+/// valid UTF-8. If the input is not a valid numeral, the error is the same as
+/// [`roman_to_integer`] would return for it, with any bytes that are not valid UTF-8 reported as
+/// [`char::REPLACEMENT_CHARACTER`].
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{roman_to_integer_bytes, Position, RomanNumeralError};
+///
+/// assert_eq!(roman_to_integer_bytes(b"MCXLII").unwrap(), 1142);
+/// assert_eq!(roman_to_integer_bytes(b" xlii\r\n").unwrap(), 42);
+/// let pos = Position::new(2, 2);
+/// assert_eq!(
+///     roman_to_integer_bytes(b"XL\xff"),
+///     Err(RomanNumeralError::InvalidCharacter { ch: char::REPLACEMENT_CHARACTER, pos })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer`].
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "trace",
+        skip_all,
+        fields(input_len = numeral.len(), style = "bytes"),
+        ret(level = "trace"),
+        err(level = "debug", Debug)
+    )
+)]
+pub fn roman_to_integer_bytes(numeral: &[u8]) -> Result<u32> {
+    let trimmed = numeral.trim_ascii();
+    if !trimmed.is_empty() && all_symbols(trimmed) {
+        symbols_to_integer(numeral)
     } else {
-        Ok(numeral)
+        // Only invalid input, or input with non-ASCII whitespace, gets this far
+        roman_to_integer(&String::from_utf8_lossy(numeral))
     }
 }
 
-fn decompose_numeral(numeral: &str) -> Result<Vec<u32>> {
-    let mut parse_state = ParseState::new(numeral);
-    let mut result: Vec<u32> = Vec::new();
-    while !parse_state.is_complete() {
-        if parse_state.remaining_to_parse.starts_with(parse_state.current_numeral().symbol) {
-            result.push(parse_state.current_numeral().value);
-            parse_state.remove_current();
-            if parse_state.current_numeral().max_group == parse_state.group_size {
-                parse_state.advance_numeral();
+/// Converts a numeral that consists only of symbols once it is trimmed of ASCII whitespace, which
+/// the caller must already have checked, into an integer.
+pub(crate) fn symbols_to_integer(numeral: &[u8]) -> Result<u32> {
+    let leading = numeral.len() - numeral.trim_ascii_start().len();
+    let start = Position::new(leading, leading);
+    ParseState::new(numeral.trim_ascii(), start).map(|rn| rn.map(|rn| rn.value)).sum()
+}
+
+/// Splits a string representing a Roman numeral into its atoms, in order.
+///
+/// The numeral must be valid, as accepted by [`roman_to_integer`]; the values of the atoms add up
+/// to the value of the numeral. Subtractive pairs such as `XL` are returned as a single atom.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::tokenize;
+///
+/// let atoms = tokenize("mcxlii").unwrap();
+/// let symbols: Vec<&str> = atoms.iter().map(|atom| atom.symbol()).collect();
+/// assert_eq!(symbols, ["M", "C", "XL", "I", "I"]);
+/// assert_eq!(atoms[2].value(), 40);
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer`].
+pub fn tokenize(numeral: &str) -> Result<Vec<RomanAtom>> {
+    let atoms = tokenize_at(numeral, Position::default())?;
+    Ok(atoms.into_iter().copied().collect())
+}
+
+/// Splits `numeral`, which starts at `start` within some larger input, into its atoms.
+pub(crate) fn tokenize_at(numeral: &str, start: Position) -> Result<Vec<&'static RomanAtom>> {
+    let (numeral, start) = validate_numeral(numeral, start)?;
+    ParseState::new(numeral.as_bytes(), start).collect()
+}
+
+/// Converts a string representing a possibly-negative Roman numeral into a signed integer.
+///
+/// A leading minus sign (`-`) marks the value as negative, and must be followed directly by the
+/// numeral, which must be valid, as accepted by [`roman_to_integer`]. Leading and trailing
+/// whitespace is trimmed, but whitespace between the sign and the numeral is not allowed.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::roman_to_signed_integer;
+///
+/// assert_eq!(roman_to_signed_integer("XIV").unwrap(), 14);
+/// assert_eq!(roman_to_signed_integer(" -xiv ").unwrap(), -14);
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer`]. An input containing only a minus sign is reported
+/// as [`EmptyString`][a], and whitespace after the minus sign as an [`InvalidCharacter`][b].
+///
+/// [a]: crate::RomanNumeralError::EmptyString
+/// [b]: crate::RomanNumeralError::InvalidCharacter
+pub fn roman_to_signed_integer(numeral: &str) -> Result<i32> {
+    let trimmed = numeral.trim();
+    match trimmed.strip_prefix('-') {
+        Some(magnitude) => {
+            let leading = numeral.len() - numeral.trim_start().len();
+            let start = Position::default().after(&numeral[..leading + 1]);
+            match magnitude.chars().next() {
+                Some(ch) if ch.is_whitespace() => {
+                    Err(RomanNumeralError::InvalidCharacter { ch, pos: start })
+                }
+                _ => roman_to_integer_at(magnitude, start).map(|val| -(val as i32)),
             }
+        }
+        None => roman_to_integer(numeral).map(|val| val as i32),
+    }
+}
+
+/// Converts a string representing a Roman numeral into an integer, accepting non-canonical forms.
+///
+/// Unlike [`roman_to_integer`], the numeral does not need to be in its canonical form. Each run of
+/// identical symbols is added to the total, unless it is followed by a larger symbol, in which
+/// case it is subtracted. This accepts additive forms such as `IIII` and `VIIII`, as well as
+/// irregular subtractive forms such as `IIX` (8) and `IC` (99). Case is ignored, and leading and
+/// trailing whitespace is trimmed.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::roman_to_integer_lenient;
+///
+/// assert_eq!(roman_to_integer_lenient("XIV").unwrap(), 14);
+/// assert_eq!(roman_to_integer_lenient("XIIII").unwrap(), 14);
+/// assert_eq!(roman_to_integer_lenient("iix").unwrap(), 8);
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralError` | Reason |
+/// | ----------------------- | ------ |
+/// | [`InvalidCharacter`][a] | `numeral` contains a character that is not a Roman numeral symbol |
+/// | [`Unparsable`][b] | the value of `numeral` is less than 1 (e.g. `IIIIIIIIIIX`) |
+/// | [`EmptyString`][c] |  `numeral` is an empty string or contains only whitespace |
+/// | [`ValueTooLarge`][d] |  the value of `numeral` is greater than [`MAX_VALUE`] |
+///
+/// [a]: crate::RomanNumeralError::InvalidCharacter
+/// [b]: crate::RomanNumeralError::Unparsable
+/// [c]: crate::RomanNumeralError::EmptyString
+/// [d]: crate::RomanNumeralError::ValueTooLarge
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "trace",
+        skip_all,
+        fields(input_len = numeral.len(), style = "lenient"),
+        ret(level = "trace"),
+        err(level = "debug", Debug)
+    )
+)]
+pub fn roman_to_integer_lenient(numeral: &str) -> Result<u32> {
+    let (numeral, _) = validate_numeral(numeral, Position::default())?;
+    let symbols = numeral.as_bytes();
+    let mut total = 0;
+    let mut pos = 0;
+    while pos < symbols.len() {
+        let value = i64::from(symbol_value(symbols[pos]));
+        let run =
+            symbols[pos..].iter().take_while(|&&b| b.eq_ignore_ascii_case(&symbols[pos])).count();
+        let next = symbols.get(pos + run).map_or(0, |&b| i64::from(symbol_value(b)));
+        if next > value {
+            total -= value * run as i64;
         } else {
-            parse_state.advance_numeral();
+            total += value * run as i64;
+        }
+        pos += run;
+    }
+    if total < i64::from(MIN_VALUE) {
+        Err(RomanNumeralError::Unparsable(numeral.to_ascii_uppercase()))
+    } else if total > i64::from(MAX_VALUE) {
+        Err(RomanNumeralError::ValueTooLarge(total.min(i64::from(u32::MAX)) as u32))
+    } else {
+        Ok(total as u32)
+    }
+}
+
+/// Parses the longest valid numeral at the start of `input`, returning its value and its length in
+/// bytes. The length is 0 if `input` does not start with a numeral.
+#[cfg(feature = "nom")]
+pub(crate) fn parse_prefix(input: &str) -> (u32, usize) {
+    let symbols = input.as_bytes();
+    let len = symbols.iter().take_while(|&&b| symbol_value(b) != 0).count();
+    let mut state = ParseState::new(&symbols[..len], Position::default());
+    let value = state.by_ref().map_while(|rn| rn.ok()).map(|rn| rn.value).sum();
+    (value, state.offset)
+}
+
+/// The index in `ATOMS` of each single-symbol atom, indexed by its upper- or lower-case byte, or
+/// `ATOMS.len()` for bytes that are not Roman numeral symbols.
+const SYMBOL_ATOMS: [usize; 256] = {
+    let mut table = [ATOMS.len(); 256];
+    let mut i = 0;
+    while i < ATOMS.len() {
+        let symbol = ATOMS[i].symbol.as_bytes();
+        if symbol.len() == 1 {
+            table[symbol[0].to_ascii_uppercase() as usize] = i;
+            table[symbol[0].to_ascii_lowercase() as usize] = i;
         }
+        i += 1;
     }
-    if parse_state.remaining_to_parse.len() > 0 {
-        Err(RomanNumeralError::Unparsable(String::from(numeral)))
+    table
+};
+
+fn symbol_value(symbol: u8) -> u32 {
+    ATOMS.get(SYMBOL_ATOMS[symbol as usize]).map_or(0, |rn| rn.value)
+}
+
+/// The atom at the start of `remaining`, which must be non-empty and contain only Roman numeral
+/// symbols.
+fn atom_at(remaining: &[u8]) -> &'static RomanAtom {
+    let index = SYMBOL_ATOMS[remaining[0] as usize];
+    let first = &ATOMS[index];
+    let next = remaining.get(1).map_or(0, |&b| symbol_value(b));
+    if first.max_group > 1 && (next == first.value * 5 || next == first.value * 10) {
+        // Subtractive pairs (e.g. `IX`) are listed shortly before the atom for their first symbol
+        ATOMS[..index].iter().rev().find(|rn| rn.value == next - first.value).unwrap_or(first)
     } else {
-        Ok(result)
+        first
     }
 }
 
+/// Trims `numeral`, which starts at `start`, checking that it only contains Roman numeral symbols.
+/// Returns the trimmed numeral and the position at which it starts.
+fn validate_numeral(numeral: &str, start: Position) -> Result<(&str, Position)> {
+    let trimmed = numeral.trim();
+    let start = start.after(&numeral[..numeral.len() - numeral.trim_start().len()]);
+    if trimmed.is_empty() {
+        return Err(RomanNumeralError::EmptyString);
+    }
+    if all_symbols(trimmed.as_bytes()) {
+        return Ok((trimmed, start));
+    }
+    let (byte, ch) = trimmed
+        .char_indices()
+        .find(|(_, ch)| !ch.is_ascii() || symbol_value(*ch as u8) == 0)
+        .expect("a numeral that is not all symbols contains an invalid character");
+    Err(RomanNumeralError::InvalidCharacter { ch, pos: start.after(&trimmed[..byte]) })
+}
+
+/// Tracks the progress of parsing a numeral, one atom at a time, in a single pass.
+///
+/// The input must already be validated, i.e. consist only of Roman numeral symbols, in either
+/// case. Parsing stops at the first error.
 #[derive(Debug)]
 struct ParseState<'a> {
-    remaining_numerals: &'static [RomanNumeral],
-    numeral_pos: usize,
-    remaining_to_parse: &'a str,
+    remaining_to_parse: &'a [u8],
+    start: Position,
+    /// The offset from `start` of the remaining input, which is the same in bytes and characters
+    /// as the input is ASCII.
+    offset: usize,
+    previous: Option<&'static RomanAtom>,
     group_size: u8,
+    max_value: u32,
 }
 
 impl<'a> ParseState<'a> {
-    fn new(to_parse: &'a str) -> Self {
+    fn new(to_parse: &'a [u8], start: Position) -> Self {
         ParseState {
-            remaining_numerals: &ATOMS[..],
-            numeral_pos: 0,
-            remaining_to_parse:
-            to_parse,
+            remaining_to_parse: to_parse,
+            start,
+            offset: 0,
+            previous: None,
             group_size: 0,
+            max_value: ATOMS[0].value,
         }
     }
 
-    fn current_numeral(&self) -> &RomanNumeral {
-        &self.remaining_numerals[0]
+    fn next_numeral(&mut self) -> Result<&'static RomanAtom> {
+        let numeral = atom_at(self.remaining_to_parse);
+        let repeated = self.previous.is_some_and(|rn| std::ptr::eq(rn, numeral));
+        if repeated && self.group_size == numeral.max_group {
+            return Err(RomanNumeralError::InvalidRepetition {
+                symbol: numeral.symbol,
+                count: self.group_size as usize + self.run_length(numeral),
+                pos: self.pos(),
+            });
+        }
+        if !repeated && numeral.value > self.max_value {
+            return Err(RomanNumeralError::OutOfOrder {
+                found: numeral.symbol,
+                expected_max: self.max_value,
+                pos: self.pos(),
+            });
+        }
+        self.group_size = if repeated { self.group_size + 1 } else { 1 };
+        self.max_value = self.max_value_after(numeral);
+        self.previous = Some(numeral);
+        self.offset += numeral.symbol.len();
+        self.remaining_to_parse = &self.remaining_to_parse[numeral.symbol.len()..];
+        Ok(numeral)
     }
 
-    fn advance_numeral(&mut self) {
-        self.numeral_pos += 1;
-        self.remaining_numerals = &ATOMS[self.numeral_pos..];
-        self.group_size = 0;
+    /// The position of the remaining input within the original input.
+    fn pos(&self) -> Position {
+        Position::new(self.start.byte + self.offset, self.start.char + self.offset)
     }
 
-    fn is_complete(&self) -> bool {
-        self.remaining_numerals.len() == 0
+    /// The number of consecutive occurrences of `numeral` at the start of the remaining input.
+    fn run_length(&self, numeral: &RomanAtom) -> usize {
+        let mut rest = self.remaining_to_parse;
+        let mut count = 0;
+        let symbol = numeral.symbol.as_bytes();
+        while rest.len() >= symbol.len() && rest[..symbol.len()].eq_ignore_ascii_case(symbol) {
+            rest = &rest[symbol.len()..];
+            count += 1;
+        }
+        count
     }
 
-    fn remove_current(&mut self) {
-        let skip = self.current_numeral().symbol.len();
-        self.remaining_to_parse = &self.remaining_to_parse[skip..];
-        self.group_size += 1;
+    /// The largest atom value that may follow `numeral` in a canonical numeral.
+    fn max_value_after(&self, numeral: &RomanAtom) -> u32 {
+        if numeral.is_subtractive() {
+            // A subtractive pair (e.g. `XC`) must be followed by atoms of the next-lower order.
+            symbol_value(numeral.symbol.as_bytes()[0]) * 9 / 10
+        } else if numeral.max_group == 1 {
+            // A five (e.g. `L`) may only be followed by ones of the next-lower order (e.g. `X`).
+            numeral.value / 5
+        } else if self.group_size < numeral.max_group {
+            numeral.value
+        } else {
+            numeral.value * 9 / 10
+        }
+    }
+}
+
+impl Iterator for ParseState<'_> {
+    type Item = Result<&'static RomanAtom>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_to_parse.is_empty() {
+            return None;
+        }
+        let result = self.next_numeral();
+        if result.is_err() {
+            self.remaining_to_parse = &[];
+        }
+        Some(result)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{roman_to_integer, RomanNumeralError};
+    use crate::{
+        decompose, integer_to_roman, roman_to_integer, roman_to_integer_bytes,
+        roman_to_integer_lenient, roman_to_integer_trusted, roman_to_signed_integer, tokenize,
+        Position, RomanNumeralError, MAX_VALUE, MIN_VALUE,
+    };
 
     #[test]
-    fn reject_invalid_format() {
+    fn trusted_matches_roman_to_integer() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let numeral = integer_to_roman(val).unwrap();
+            assert_eq!(roman_to_integer_trusted(&numeral), val, "{}", numeral);
+            assert_eq!(roman_to_integer_trusted(&numeral.to_lowercase()), val, "{}", numeral);
+        }
+    }
+
+    #[test]
+    fn trusted_does_not_panic_on_invalid_input() {
+        assert_eq!(roman_to_integer_trusted(""), 0);
+        assert_eq!(roman_to_integer_trusted("X!V"), 15);
+        assert_eq!(roman_to_integer_trusted("IIIIIIX"), 14);
+        assert_eq!(roman_to_integer_trusted("IM"), 999);
+        assert_eq!(roman_to_integer_trusted("IIV"), 5);
+        assert_eq!(roman_to_integer_trusted(&"M".repeat(5_000_000)), 5_000_000_000u64 as u32);
+    }
+
+    #[test]
+    fn bytes_match_roman_to_integer() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let numeral = integer_to_roman(val).unwrap();
+            assert_eq!(roman_to_integer_bytes(numeral.as_bytes()), Ok(val), "{}", numeral);
+        }
+        let values = ["", " \t", "\u{3000}xiv\u{3000}", "MMDL1", " IIII", "CMD", "\tmmmmm", "é"];
+        for val in values.iter() {
+            assert_eq!(roman_to_integer_bytes(val.as_bytes()), roman_to_integer(val), "{}", val);
+        }
+    }
+
+    #[test]
+    fn reject_bytes_that_are_not_utf8() {
+        let ch = char::REPLACEMENT_CHARACTER;
+        let pos = Position::new(0, 0);
+        assert_eq!(
+            roman_to_integer_bytes(b"\x80XIV"),
+            Err(RomanNumeralError::InvalidCharacter { ch, pos })
+        );
+        let pos = Position::new(4, 4);
+        assert_eq!(
+            roman_to_integer_bytes(b" XIV\xc3"),
+            Err(RomanNumeralError::InvalidCharacter { ch, pos })
+        );
+    }
+
+    #[test]
+    fn reject_invalid_characters() {
         let invalid_values = [
-            "ABCDEF",
-            "MMDL1",
-            "934;-)",
-            "CMM",
-            "ID",
-            "MMCCD",
-            "XLXL",
-            "IIII",
-            "VV",
-            "DDIV"
+            ("ABCDEF", 'A', 0, 0),
+            ("MMDL1", '1', 4, 4),
+            ("934;-)", '9', 0, 0),
+            (" xiv!", '!', 4, 4),
+            ("\u{3000}XIV!", '!', 6, 4),
         ];
-        for val in invalid_values.iter() {
-            match roman_to_integer(*val) {
-                Err(RomanNumeralError::Unparsable(_)) => (),
-                Err(e) => panic!("wrong kind of error: {:?}", e),
-                Ok(int_val) => panic!("unexpected ok result: {} = {}", val, int_val),
-            }
+        for (val, ch, byte, char) in invalid_values.iter() {
+            let pos = Position::new(*byte, *char);
+            let expected = RomanNumeralError::InvalidCharacter { ch: *ch, pos };
+            assert_eq!(roman_to_integer(val), Err(expected), "{}", val);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_repetitions() {
+        let invalid_values = [
+            ("XLXL", "XL", 2, 2),
+            ("IIII", "I", 4, 3),
+            ("VV", "V", 2, 1),
+            ("DDIV", "D", 2, 1),
+            ("MMMMM", "M", 5, 3),
+            ("\tmmmmm", "M", 5, 4),
+        ];
+        for (val, symbol, count, pos) in invalid_values.iter() {
+            let pos = Position::new(*pos, *pos);
+            let expected = RomanNumeralError::InvalidRepetition { symbol, count: *count, pos };
+            assert_eq!(roman_to_integer(val), Err(expected), "{}", val);
         }
     }
 
     #[test]
+    fn reject_out_of_order_symbols() {
+        let invalid_values = [
+            ("CMM", "M", 90, 2),
+            ("ID", "D", 1, 1),
+            ("MMCCD", "CD", 100, 3),
+            ("CMD", "D", 90, 2),
+            ("CDC", "C", 90, 2),
+            ("DCD", "CD", 100, 1),
+            ("XCX", "X", 9, 2),
+            ("IXV", "V", 0, 2),
+            ("IVI", "I", 0, 2),
+            ("MCMC", "C", 90, 3),
+            ("  MCMC  ", "C", 90, 5),
+        ];
+        for (val, found, expected_max, pos) in invalid_values.iter() {
+            let pos = Position::new(*pos, *pos);
+            let expected =
+                RomanNumeralError::OutOfOrder { found, expected_max: *expected_max, pos };
+            assert_eq!(roman_to_integer(val), Err(expected), "{}", val);
+        }
+    }
+
+    #[test]
+    fn convert_all_canonical_values() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            assert_eq!(roman_to_integer(&integer_to_roman(val).unwrap()), Ok(val));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::explicit_auto_deref)]
     fn reject_empty_string() {
         for val in ["", "   ", "\t", "\n"].iter() {
             match roman_to_integer(*val) {
@@ -177,6 +600,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::explicit_auto_deref, clippy::single_match)]
     fn allow_lowercase_and_whitespace() {
         for val in ["  MCXLII", "CII  ", "  X  ", "V\n", "mcmxl", " cclxi ", "mmCCxXiI"].iter() {
             match roman_to_integer(*val) {
@@ -186,6 +610,108 @@ mod tests {
         }
     }
 
+    #[test]
+    fn convert_signed_values() {
+        assert_eq!(roman_to_signed_integer("XLII").unwrap(), 42);
+        assert_eq!(roman_to_signed_integer("-XLII").unwrap(), -42);
+        assert_eq!(roman_to_signed_integer("  -mmmcmxcix ").unwrap(), -3999);
+    }
+
+    #[test]
+    fn reject_invalid_signed_values() {
+        assert_eq!(roman_to_signed_integer("-"), Err(RomanNumeralError::EmptyString));
+        for val in ["--X", "X-", "+X"].iter() {
+            match roman_to_signed_integer(val) {
+                Err(RomanNumeralError::InvalidCharacter { ch: '-', .. }) => (),
+                Err(RomanNumeralError::InvalidCharacter { ch: '+', .. }) => (),
+                Err(e) => panic!("wrong kind of error: {:?}", e),
+                Ok(int_val) => panic!("unexpected ok result: {} = {}", val, int_val),
+            }
+        }
+    }
+
+    #[test]
+    fn tokenize_all_canonical_values() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let numeral = integer_to_roman(val).unwrap();
+            assert_eq!(tokenize(&numeral), decompose(val), "{}", numeral);
+        }
+    }
+
+    #[test]
+    fn reject_tokenizing_invalid_values() {
+        assert_eq!(tokenize(" "), Err(RomanNumeralError::EmptyString));
+        let pos = Position::new(2, 2);
+        assert_eq!(
+            tokenize("XVV"),
+            Err(RomanNumeralError::InvalidRepetition { symbol: "V", count: 2, pos })
+        );
+    }
+
+    #[test]
+    fn report_signed_positions_in_original_input() {
+        let pos = Position::new(3, 3);
+        assert_eq!(
+            roman_to_signed_integer(" -XIIII"),
+            Err(RomanNumeralError::InvalidRepetition {
+                symbol: "I",
+                count: 4,
+                pos: Position::new(6, 6)
+            })
+        );
+        assert_eq!(
+            roman_to_signed_integer(" -X1"),
+            Err(RomanNumeralError::InvalidCharacter { ch: '1', pos })
+        );
+    }
+
+    #[test]
+    fn reject_whitespace_after_minus_sign() {
+        let pos = Position::new(2, 2);
+        assert_eq!(
+            roman_to_signed_integer(" - XIV"),
+            Err(RomanNumeralError::InvalidCharacter { ch: ' ', pos })
+        );
+        assert_eq!(
+            roman_to_signed_integer("-\tXIV"),
+            Err(RomanNumeralError::InvalidCharacter { ch: '\t', pos: Position::new(1, 1) })
+        );
+        assert_eq!(roman_to_signed_integer(" - "), Err(RomanNumeralError::EmptyString));
+        assert_eq!(roman_to_signed_integer("-XIV\n"), Ok(-14));
+    }
+
+    #[test]
+    fn convert_lenient_values() {
+        let values = [
+            ("MCXLII", 1142),
+            ("IIII", 4),
+            ("VIIII", 9),
+            ("MDCCCCLXXXXVIIII", 1999),
+            ("IIX", 8),
+            ("XIIX", 18),
+            ("IC", 99),
+            ("mim", 1999),
+            ("  xxxxii ", 42),
+        ];
+        for (val, expected) in values.iter() {
+            assert_eq!(roman_to_integer_lenient(val), Ok(*expected), "{}", val);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_lenient_values() {
+        assert_eq!(roman_to_integer_lenient(" "), Err(RomanNumeralError::EmptyString));
+        assert_eq!(roman_to_integer_lenient("MMMM"), Err(RomanNumeralError::ValueTooLarge(4000)));
+        assert_eq!(
+            roman_to_integer_lenient("IIIIIIIIIIX"),
+            Err(RomanNumeralError::Unparsable(String::from("IIIIIIIIIIX")))
+        );
+        assert_eq!(
+            roman_to_integer_lenient(" X1"),
+            Err(RomanNumeralError::InvalidCharacter { ch: '1', pos: Position::new(2, 2) })
+        );
+    }
+
     mod simple {
         use crate::roman_to_integer;
 
@@ -0,0 +1,270 @@
+use std::convert::TryInto;
+
+use super::itor::{check_range, to_u32};
+use super::{roman_to_integer_lenient, Result, RomanNumeralError};
+
+/// Each symbol with its value, from the largest down. Symbols for powers of ten are at even
+/// indices, and symbols for fives are at odd indices.
+const SYMBOLS: [(char, u32); 7] =
+    [('M', 1000), ('D', 500), ('C', 100), ('L', 50), ('X', 10), ('V', 5), ('I', 1)];
+
+/// The forms of numeral written by the `ROMAN(number, form)` function of spreadsheets such as
+/// Excel and LibreOffice Calc.
+///
+/// Each form after [`Classic`][SpreadsheetForm::Classic] relaxes the rules for subtractive pairs
+/// a little further, so that the numerals get shorter: 450 is `CDL` in the classic form, but `LD`
+/// in every other form, and 499 goes from `CDXCIX` to `LDVLIV`, `XDIX`, `VDIV` and finally `ID`.
+/// The numeric value of each form is its `form` argument to `ROMAN`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SpreadsheetForm {
+    /// Form 0, the canonical form, as written by [`integer_to_roman`][crate::integer_to_roman].
+    /// This is the default, as when `form` is omitted or `TRUE`.
+    #[default]
+    Classic = 0,
+    /// Form 1, which allows a subtractive pair to subtract the next smaller five (e.g. `LD`).
+    Concise = 1,
+    /// Form 2, which allows a subtractive pair to subtract up to two steps further.
+    MoreConcise = 2,
+    /// Form 3, which allows a subtractive pair to subtract up to three steps further.
+    EvenMoreConcise = 3,
+    /// Form 4, the shortest form, which allows a subtractive pair to subtract up to four steps
+    /// further. This is the form used when `form` is `FALSE`.
+    Simplified = 4,
+}
+
+impl SpreadsheetForm {
+    /// The form with the given `form` argument to `ROMAN`, if it is from 0 to 4.
+    ///
+    /// ```
+    /// use numeris::SpreadsheetForm;
+    ///
+    /// assert_eq!(SpreadsheetForm::new(4), Some(SpreadsheetForm::Simplified));
+    /// assert_eq!(SpreadsheetForm::new(5), None);
+    /// ```
+    pub fn new(form: u8) -> Option<Self> {
+        match form {
+            0 => Some(SpreadsheetForm::Classic),
+            1 => Some(SpreadsheetForm::Concise),
+            2 => Some(SpreadsheetForm::MoreConcise),
+            3 => Some(SpreadsheetForm::EvenMoreConcise),
+            4 => Some(SpreadsheetForm::Simplified),
+            _ => None,
+        }
+    }
+
+    /// The `form` argument to `ROMAN` for this form.
+    pub fn number(self) -> u8 {
+        self as u8
+    }
+}
+
+/// The form selected by a logical `form` argument to `ROMAN`: `TRUE` is the classic form, and
+/// `FALSE` is the simplified form.
+impl From<bool> for SpreadsheetForm {
+    fn from(form: bool) -> Self {
+        if form {
+            SpreadsheetForm::Classic
+        } else {
+            SpreadsheetForm::Simplified
+        }
+    }
+}
+
+/// Converts an integer into a string representing a Roman numeral, exactly as the spreadsheet
+/// function `ROMAN(val, form)` does.
+///
+/// The numeral is identical to the one written by Excel or LibreOffice Calc for the same
+/// arguments. The only difference is that 0 is rejected, as by
+/// [`integer_to_roman`][crate::integer_to_roman], where `ROMAN` returns an empty string.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{integer_to_spreadsheet_roman, SpreadsheetForm};
+///
+/// let form = SpreadsheetForm::Classic;
+/// assert_eq!(integer_to_spreadsheet_roman(499, form).unwrap(), "CDXCIX");
+/// let form = SpreadsheetForm::Concise;
+/// assert_eq!(integer_to_spreadsheet_roman(450, form).unwrap(), "LD");
+/// let form = SpreadsheetForm::Simplified;
+/// assert_eq!(integer_to_spreadsheet_roman(499, form).unwrap(), "ID");
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralErrorKind` | Reason |
+/// | ----------------------- | ------ |
+/// | [`ValueTooSmall`][a] | `val` is too small to be converted to a Roman numeral |
+/// | [`ValueTooLarge`][b] |  `val` is too large to be converted to a Roman numeral |
+///
+/// [a]: crate::RomanNumeralError::ValueTooSmall
+/// [b]: crate::RomanNumeralError::ValueTooLarge
+pub fn integer_to_spreadsheet_roman<T>(val: T, form: SpreadsheetForm) -> Result<String>
+where
+    T: TryInto<u32> + PartialOrd + Default,
+{
+    let val = to_u32(val)?;
+    check_range(val)?;
+    Ok(build_spreadsheet_roman(val, form))
+}
+
+/// Converts a string representing a Roman numeral in the given spreadsheet form into an integer.
+///
+/// Only the numerals written by [`integer_to_spreadsheet_roman`] in `form` are accepted, so that
+/// each value has exactly one numeral in each form. Case is ignored, and leading and trailing
+/// whitespace is trimmed.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{spreadsheet_roman_to_integer, SpreadsheetForm};
+///
+/// let form = SpreadsheetForm::MoreConcise;
+/// assert_eq!(spreadsheet_roman_to_integer("XDIX", form).unwrap(), 499);
+/// assert!(spreadsheet_roman_to_integer("CDXCIX", form).is_err());
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralError` | Reason |
+/// | ----------------------- | ------ |
+/// | [`Unparsable`][a] | `numeral` is not written in `form` |
+///
+/// Any error returned by [`roman_to_integer_lenient`] may also be returned.
+///
+/// [a]: crate::RomanNumeralError::Unparsable
+pub fn spreadsheet_roman_to_integer(numeral: &str, form: SpreadsheetForm) -> Result<u32> {
+    let value = roman_to_integer_lenient(numeral)?;
+    let numeral = numeral.trim().to_ascii_uppercase();
+    if build_spreadsheet_roman(value, form) == numeral {
+        Ok(value)
+    } else {
+        Err(RomanNumeralError::Unparsable(numeral))
+    }
+}
+
+/// Writes `val`, which must already be in range, in `form`, following the same steps as the
+/// `ROMAN` function of LibreOffice Calc.
+pub(crate) fn build_spreadsheet_roman(mut val: u32, form: SpreadsheetForm) -> String {
+    let last = SYMBOLS.len() - 1;
+    let mut result = String::new();
+    for place in (0..SYMBOLS.len()).step_by(2) {
+        let (one, place_value) = SYMBOLS[place];
+        let digit = val / place_value;
+        if digit % 5 == 4 {
+            // Subtract from the next five or ten, using smaller symbols as far as the form allows,
+            // as long as they do not subtract more than remains
+            let larger = if digit == 4 { place - 1 } else { place - 2 };
+            let mut smaller = place;
+            for _ in 0..form.number() {
+                if smaller == last || SYMBOLS[larger].1 - SYMBOLS[smaller + 1].1 > val {
+                    break;
+                }
+                smaller += 1;
+            }
+            result.push(SYMBOLS[smaller].0);
+            result.push(SYMBOLS[larger].0);
+            val = val + SYMBOLS[smaller].1 - SYMBOLS[larger].1;
+        } else {
+            if digit > 4 {
+                result.push(SYMBOLS[place - 1].0);
+            }
+            result.extend(std::iter::repeat_n(one, (digit % 5) as usize));
+            val %= place_value;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        integer_to_roman, integer_to_spreadsheet_roman, spreadsheet_roman_to_integer,
+        RomanNumeralError, SpreadsheetForm, MAX_VALUE, MIN_VALUE,
+    };
+
+    const FORMS: [SpreadsheetForm; 5] = [
+        SpreadsheetForm::Classic,
+        SpreadsheetForm::Concise,
+        SpreadsheetForm::MoreConcise,
+        SpreadsheetForm::EvenMoreConcise,
+        SpreadsheetForm::Simplified,
+    ];
+
+    fn all_forms(val: u32) -> Vec<String> {
+        FORMS.iter().map(|&form| integer_to_spreadsheet_roman(val, form).unwrap()).collect()
+    }
+
+    #[test]
+    fn select_forms() {
+        for (number, &form) in FORMS.iter().enumerate() {
+            assert_eq!(SpreadsheetForm::new(number as u8), Some(form));
+            assert_eq!(form.number(), number as u8);
+        }
+        assert_eq!(SpreadsheetForm::new(5), None);
+        assert_eq!(SpreadsheetForm::default(), SpreadsheetForm::Classic);
+        assert_eq!(SpreadsheetForm::from(true), SpreadsheetForm::Classic);
+        assert_eq!(SpreadsheetForm::from(false), SpreadsheetForm::Simplified);
+    }
+
+    #[test]
+    fn write_numerals_as_spreadsheets_do() {
+        assert_eq!(all_forms(499), ["CDXCIX", "LDVLIV", "XDIX", "VDIV", "ID"]);
+        assert_eq!(all_forms(450), ["CDL", "LD", "LD", "LD", "LD"]);
+        assert_eq!(all_forms(999), ["CMXCIX", "LMVLIV", "XMIX", "VMIV", "IM"]);
+        assert_eq!(all_forms(1995), ["MCMXCV", "MLMVL", "MXMV", "MVM", "MVM"]);
+        assert_eq!(all_forms(3999), ["MMMCMXCIX", "MMMLMVLIV", "MMMXMIX", "MMMVMIV", "MMMIM"]);
+        assert_eq!(all_forms(1142), vec!["MCXLII"; 5]);
+    }
+
+    #[test]
+    fn write_classic_numerals_canonically() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let numeral = integer_to_spreadsheet_roman(val, SpreadsheetForm::Classic).unwrap();
+            assert_eq!(numeral, integer_to_roman(val).unwrap());
+        }
+    }
+
+    #[test]
+    fn reject_values_out_of_range() {
+        let form = SpreadsheetForm::Simplified;
+        assert_eq!(integer_to_spreadsheet_roman(0, form), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(
+            integer_to_spreadsheet_roman(4000, form),
+            Err(RomanNumeralError::ValueTooLarge(4000))
+        );
+        assert_eq!(
+            integer_to_spreadsheet_roman(-1, form),
+            Err(RomanNumeralError::ValueTooSmall(0))
+        );
+    }
+
+    #[test]
+    fn round_trip_all_forms() {
+        for &form in FORMS.iter() {
+            for val in MIN_VALUE..=MAX_VALUE {
+                let numeral = integer_to_spreadsheet_roman(val, form).unwrap();
+                assert_eq!(spreadsheet_roman_to_integer(&numeral, form), Ok(val), "{}", numeral);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_only_the_given_form() {
+        let form = SpreadsheetForm::Simplified;
+        assert_eq!(spreadsheet_roman_to_integer(" id ", form), Ok(499));
+        assert_eq!(
+            spreadsheet_roman_to_integer("CDXCIX", form),
+            Err(RomanNumeralError::Unparsable(String::from("CDXCIX")))
+        );
+        assert_eq!(
+            spreadsheet_roman_to_integer("ID", SpreadsheetForm::Classic),
+            Err(RomanNumeralError::Unparsable(String::from("ID")))
+        );
+        assert_eq!(spreadsheet_roman_to_integer("", form), Err(RomanNumeralError::EmptyString));
+        assert!(matches!(
+            spreadsheet_roman_to_integer("IDK", form),
+            Err(RomanNumeralError::InvalidCharacter { ch: 'K', .. })
+        ));
+    }
+}
@@ -0,0 +1,124 @@
+//! Storing [`RomanNumeral`]s in databases with `sqlx`. Requires the `sqlx` feature.
+//!
+//! A `RomanNumeral` implements the `sqlx` `Type`, `Encode` and `Decode` traits for any database,
+//! and is stored in a text column as its numeral.
+
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode, Type};
+
+use super::RomanNumeral;
+
+/// A `RomanNumeral` is stored as its numeral, in any column that can hold a string (e.g. `TEXT`
+/// or `VARCHAR`), so that it is readable by anyone looking at the database directly. Decoding
+/// a value that is not a valid numeral fails with the reason that it is invalid.
+impl<DB: Database> Type<DB> for RomanNumeral
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for RomanNumeral
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.to_string().encode(buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for RomanNumeral
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(<&str as Decode<DB>>::decode(value)?.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+    use std::thread::{self, Thread};
+
+    use sqlx::{Connection, Error, SqliteConnection};
+
+    use crate::RomanNumeral;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Runs a future on the current thread. SQLite connections do their work on a thread of their
+    /// own, so no async runtime is needed.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+
+    async fn with_table() -> Result<SqliteConnection, Error> {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+        sqlx::query("CREATE TABLE monarchs (name TEXT, ordinal TEXT)").execute(&mut conn).await?;
+        Ok(conn)
+    }
+
+    #[test]
+    fn store_numerals_as_text() {
+        block_on(async {
+            let mut conn = with_table().await.unwrap();
+            sqlx::query("INSERT INTO monarchs VALUES ('Henry', ?)")
+                .bind(RomanNumeral::new(8).unwrap())
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            let (text, ordinal): (String, RomanNumeral) =
+                sqlx::query_as("SELECT ordinal, ordinal FROM monarchs")
+                    .fetch_one(&mut conn)
+                    .await
+                    .unwrap();
+            assert_eq!(text, "VIII");
+            assert_eq!(ordinal.value(), 8);
+        });
+    }
+
+    #[test]
+    fn reject_invalid_numerals() {
+        block_on(async {
+            let mut conn = with_table().await.unwrap();
+            sqlx::query("INSERT INTO monarchs VALUES ('Louis', 'XIIII')")
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            let error = sqlx::query_scalar::<_, RomanNumeral>("SELECT ordinal FROM monarchs")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap_err();
+            assert!(matches!(error, Error::ColumnDecode { .. }));
+            assert!(error.to_string().ends_with("I is repeated 4 times in a row"), "{}", error);
+        });
+    }
+}
@@ -0,0 +1,292 @@
+use std::io::{self, Read, Write};
+
+use super::text::{arabize_after, context_of, is_word_char};
+use super::{romanize_text, NumeralContext};
+
+/// The number of bytes read from the underlying reader at a time.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// The transformation applied to a text by a [`RomanRewriter`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Rewrite {
+    /// Replaces integers with Roman numerals, as by [`romanize_text`].
+    Romanize,
+    /// Replaces Roman numerals in a numeric context with integers, as by
+    /// [`arabize_text`][crate::arabize_text].
+    Arabize,
+    /// Replaces every Roman numeral with an integer, as by
+    /// [`arabize_text_with`][crate::arabize_text_with] with [`NumeralContext::Ignored`].
+    ArabizeAll,
+}
+
+impl Rewrite {
+    /// Applies this transformation to a complete text.
+    pub fn apply(self, text: &str) -> String {
+        self.apply_after("", text)
+    }
+
+    /// Applies this transformation to `text`, which follows `before` in some larger text.
+    fn apply_after(self, before: &str, text: &str) -> String {
+        match self {
+            Rewrite::Romanize => romanize_text(text),
+            Rewrite::Arabize => arabize_after(before, text, NumeralContext::Required),
+            Rewrite::ArabizeAll => arabize_after(before, text, NumeralContext::Ignored),
+        }
+    }
+}
+
+/// An adapter which rewrites the numbers in a stream of text, as it is read or written.
+///
+/// When wrapping a reader, the rewritten text is read from the `RomanRewriter`. When wrapping a
+/// writer, text written to the `RomanRewriter` is rewritten before it is passed on. Either way,
+/// only a small amount of text is held in memory at once, so texts of any size can be rewritten.
+/// The result is always the same as applying the [`Rewrite`] to the whole text at once, no
+/// matter how the text is split into chunks.
+///
+/// Text is held back until it is followed by a character that cannot be part of a number (e.g.
+/// a space), since any text before that can be rewritten without knowing what comes after it.
+/// The last few words that have been rewritten are also kept, since whether a numeral is
+/// arabized depends on the word before it.
+/// The text must be valid UTF-8; otherwise an error of kind [`io::ErrorKind::InvalidData`] is
+/// returned.
+///
+/// When writing, [`finish`][RomanRewriter::finish] must be called once all of the text has been
+/// written, to rewrite and write any text that is still being held back.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{Read, Write};
+///
+/// use numeris::{Rewrite, RomanRewriter};
+///
+/// let mut rewriter = RomanRewriter::new("Chapter 42, part 7".as_bytes(), Rewrite::Romanize);
+/// let mut text = String::new();
+/// rewriter.read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "Chapter XLII, part VII");
+///
+/// let mut rewriter = RomanRewriter::new(Vec::new(), Rewrite::Arabize);
+/// rewriter.write_all(b"Chapter XL").unwrap();
+/// rewriter.write_all(b"II, part VII").unwrap();
+/// let text = rewriter.finish().unwrap();
+/// assert_eq!(text, b"Chapter 42, part 7");
+/// ```
+#[derive(Debug)]
+pub struct RomanRewriter<T> {
+    inner: T,
+    rewrite: Rewrite,
+    /// Text which has not yet been rewritten.
+    pending: Vec<u8>,
+    /// The end of the text which has been rewritten, as it was before it was rewritten.
+    context: String,
+    /// Rewritten text which has not yet been read.
+    output: Vec<u8>,
+    output_pos: usize,
+    eof: bool,
+}
+
+impl<T> RomanRewriter<T> {
+    /// Creates a new adapter that applies `rewrite` to the text read from or written to `inner`.
+    pub fn new(inner: T, rewrite: Rewrite) -> Self {
+        RomanRewriter {
+            inner,
+            rewrite,
+            pending: Vec::new(),
+            context: String::new(),
+            output: Vec::new(),
+            output_pos: 0,
+            eof: false,
+        }
+    }
+
+    /// Gets a reference to the underlying reader or writer.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwraps this adapter, returning the underlying reader or writer.
+    ///
+    /// Any text that is being held back is lost; when writing, use
+    /// [`finish`][RomanRewriter::finish] instead.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Rewrites and removes as much of the pending text as can be rewritten, or all of it at the
+    /// end of the text.
+    fn rewrite_pending(&mut self, at_end: bool) -> io::Result<Vec<u8>> {
+        let valid_len = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            // A character may be split across chunks
+            Err(e) if e.error_len().is_none() && !at_end => e.valid_up_to(),
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+        let text = std::str::from_utf8(&self.pending[..valid_len]).expect("validated above");
+        let complete_len = if at_end {
+            text.len()
+        } else {
+            text.char_indices()
+                .rev()
+                .find(|&(_, ch)| !is_word_char(ch) && ch != '.' && ch != ',')
+                .map_or(0, |(i, ch)| i + ch.len_utf8())
+        };
+        let complete = &text[..complete_len];
+        let rewritten = self.rewrite.apply_after(&self.context, complete);
+        self.context.push_str(complete);
+        self.context = String::from(context_of(&self.context));
+        self.pending.drain(..complete_len);
+        Ok(rewritten.into_bytes())
+    }
+}
+
+impl<R: Read> RomanRewriter<R> {
+    fn fill_output(&mut self) -> io::Result<()> {
+        let len = self.pending.len();
+        self.pending.resize(len + CHUNK_SIZE, 0);
+        let read = self.inner.read(&mut self.pending[len..]);
+        self.pending.truncate(len + read.as_ref().map_or(0, |read| *read));
+        self.eof = read? == 0;
+        self.output = self.rewrite_pending(self.eof)?;
+        self.output_pos = 0;
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for RomanRewriter<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.output_pos == self.output.len() {
+            if self.eof {
+                return Ok(0);
+            }
+            self.fill_output()?;
+        }
+        let available = &self.output[self.output_pos..];
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.output_pos += len;
+        Ok(len)
+    }
+}
+
+impl<W: Write> RomanRewriter<W> {
+    /// Rewrites and writes any text that is still being held back, and flushes the underlying
+    /// writer, returning it.
+    pub fn finish(mut self) -> io::Result<W> {
+        let rewritten = self.rewrite_pending(true)?;
+        self.inner.write_all(&rewritten)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for RomanRewriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        let rewritten = self.rewrite_pending(false)?;
+        self.inner.write_all(&rewritten)?;
+        Ok(buf.len())
+    }
+
+    /// Flushes the underlying writer. Text that is being held back is not written, as it may
+    /// depend on text that has not been written yet.
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read, Write};
+
+    use crate::{Rewrite, RomanRewriter};
+
+    /// A reader which returns at most `chunk` bytes at a time.
+    struct Chunked<'a> {
+        data: &'a [u8],
+        chunk: usize,
+    }
+
+    impl Read for Chunked<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.chunk.min(buf.len()).min(self.data.len());
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+            Ok(len)
+        }
+    }
+
+    fn read_chunked(text: &[u8], chunk: usize, rewrite: Rewrite) -> io::Result<String> {
+        let mut result = String::new();
+        RomanRewriter::new(Chunked { data: text, chunk }, rewrite).read_to_string(&mut result)?;
+        Ok(result)
+    }
+
+    fn write_chunked(text: &[u8], chunk: usize, rewrite: Rewrite) -> io::Result<String> {
+        let mut rewriter = RomanRewriter::new(Vec::new(), rewrite);
+        for part in text.chunks(chunk) {
+            rewriter.write_all(part)?;
+        }
+        Ok(String::from_utf8(rewriter.finish()?).unwrap())
+    }
+
+    const TEXT: &str = "In 1066, 7000 men (ünïcödé) fought 9 hours, at 3.14 per 1,000; note 14. \
+                        Then I read Book 2 by Henry 8 and Elizabeth 1 on Act  3.";
+
+    #[test]
+    fn match_whole_text_for_any_chunk_size() {
+        for rewrite in [Rewrite::Romanize, Rewrite::Arabize, Rewrite::ArabizeAll].iter() {
+            let text = match rewrite {
+                Rewrite::Romanize => String::from(TEXT),
+                Rewrite::Arabize | Rewrite::ArabizeAll => Rewrite::Romanize.apply(TEXT),
+            };
+            let expected = rewrite.apply(&text);
+            for chunk in 1..=text.len() {
+                let bytes = text.as_bytes();
+                assert_eq!(read_chunked(bytes, chunk, *rewrite).unwrap(), expected, "{}", chunk);
+                assert_eq!(write_chunked(bytes, chunk, *rewrite).unwrap(), expected, "{}", chunk);
+            }
+        }
+    }
+
+    #[test]
+    fn rewrite_large_streams() {
+        let text = "Chapter 12. ".repeat(10_000);
+        let expected = "Chapter XII. ".repeat(10_000);
+        assert_eq!(read_chunked(text.as_bytes(), 5000, Rewrite::Romanize).unwrap(), expected);
+        let mut rewriter = RomanRewriter::new(text.as_bytes(), Rewrite::Romanize);
+        let mut result = String::new();
+        rewriter.read_to_string(&mut result).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn hold_back_incomplete_words() {
+        let mut rewriter = RomanRewriter::new(Vec::new(), Rewrite::Romanize);
+        rewriter.write_all(b"page 3.").unwrap();
+        assert_eq!(rewriter.get_ref(), b"page ");
+        rewriter.write_all(b"5 and 4").unwrap();
+        assert_eq!(rewriter.get_ref(), b"page 3.5 and ");
+        assert_eq!(rewriter.finish().unwrap(), b"page 3.5 and IV");
+    }
+
+    #[test]
+    fn keep_context_across_chunks() {
+        let mut rewriter = RomanRewriter::new(Vec::new(), Rewrite::Arabize);
+        rewriter.write_all(b"I, ").unwrap();
+        rewriter.write_all(b"Chapter ").unwrap();
+        rewriter.write_all(b"IV").unwrap();
+        rewriter.write_all(b" and MIX").unwrap();
+        assert_eq!(rewriter.finish().unwrap(), b"I, Chapter 4 and MIX");
+        let mut rewriter = RomanRewriter::new(Vec::new(), Rewrite::ArabizeAll);
+        rewriter.write_all(b"I, Chapter IV and MIX").unwrap();
+        assert_eq!(rewriter.finish().unwrap(), b"1, Chapter 4 and 1009");
+    }
+
+    #[test]
+    fn reject_invalid_utf8() {
+        let error = read_chunked(b"XIV \xff", 2, Rewrite::Arabize).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let error = write_chunked(b"XIV \xc3", 2, Rewrite::Arabize).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt;
+
+use super::itor::numeral_len;
+use super::{
+    integer_to_roman, is_canonical, roman_to_integer, roman_to_integer_lenient, RomanNumeralError,
+    MAX_VALUE, MIN_VALUE,
+};
+
+/// The largest edit distance at which a canonical numeral is still considered a suggestion.
+const MAX_DISTANCE: usize = 2;
+/// The number of suggestions included in the message of an [`ErrorWithSuggestions`].
+const MAX_DISPLAYED: usize = 3;
+
+/// An error in a numeral, together with the canonical numerals that may have been meant, as listed
+/// by [`suggestions`].
+///
+/// This is returned by [`roman_to_integer_with_suggestions`], and can be created for the error
+/// returned by any other function that parses a numeral with [`ErrorWithSuggestions::new`]. Its
+/// message is the message of the error, followed by the first three suggestions.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{normalize, ErrorWithSuggestions};
+///
+/// let error = ErrorWithSuggestions::new("XIV!", normalize("XIV!").unwrap_err());
+/// assert_eq!(error.suggestions(), ["XIV"]);
+/// assert_eq!(
+///     error.to_string(),
+///     "'!' at position 4 is not a Roman numeral symbol; did you mean XIV?"
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorWithSuggestions {
+    error: RomanNumeralError,
+    suggestions: Vec<String>,
+}
+
+impl ErrorWithSuggestions {
+    /// Adds the [`suggestions`] for `numeral` to `error`, which is an error in parsing it.
+    pub fn new(numeral: &str, error: RomanNumeralError) -> Self {
+        ErrorWithSuggestions { error, suggestions: suggestions(numeral) }
+    }
+
+    /// The error in the numeral.
+    pub fn error(&self) -> &RomanNumeralError {
+        &self.error
+    }
+
+    /// The canonical numerals that may have been meant, most likely first, which may be empty.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    /// The first three suggestions, as a question, e.g. `did you mean XVIII, XIX or XIII?`, or
+    /// `None` if there are no suggestions.
+    pub fn hint(&self) -> Option<String> {
+        let (last, rest) =
+            self.suggestions[..self.suggestions.len().min(MAX_DISPLAYED)].split_last()?;
+        if rest.is_empty() {
+            Some(format!("did you mean {}?", last))
+        } else {
+            Some(format!("did you mean {} or {}?", rest.join(", "), last))
+        }
+    }
+
+    /// Discards the suggestions, returning only the error.
+    pub fn into_error(self) -> RomanNumeralError {
+        self.error
+    }
+}
+
+impl fmt::Display for ErrorWithSuggestions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.hint() {
+            Some(hint) => write!(f, "{}; {}", self.error, hint),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for ErrorWithSuggestions {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<ErrorWithSuggestions> for RomanNumeralError {
+    fn from(error: ErrorWithSuggestions) -> Self {
+        error.error
+    }
+}
+
+/// Converts a string representing a Roman numeral into an integer, suggesting what may have been
+/// meant if it is not valid.
+///
+/// This behaves exactly like [`roman_to_integer`], but any error comes with the [`suggestions`]
+/// for `numeral`. The suggestions are only computed when `numeral` is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{roman_to_integer_with_suggestions, RomanNumeralError};
+///
+/// assert_eq!(roman_to_integer_with_suggestions("XIX"), Ok(19));
+/// let error = roman_to_integer_with_suggestions("XIIX").unwrap_err();
+/// assert!(matches!(error.error(), RomanNumeralError::OutOfOrder { found: "IX", .. }));
+/// assert_eq!(error.suggestions()[..2], ["XVIII", "XIX"]);
+/// assert_eq!(
+///     error.to_string(),
+///     "IX found where at most 1 was expected; did you mean XVIII, XIX or XIII?"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer`], with its suggestions.
+pub fn roman_to_integer_with_suggestions(
+    numeral: &str,
+) -> std::result::Result<u32, ErrorWithSuggestions> {
+    roman_to_integer(numeral).map_err(|e| ErrorWithSuggestions::new(numeral, e))
+}
+
+/// Suggests canonical Roman numerals that may have been meant by an invalid numeral.
+///
+/// If `numeral` can be parsed by [`roman_to_integer_lenient`], its canonical form is always the
+/// first suggestion (e.g. `XVIII` for `XIIX`). This is followed by the canonical numerals that are
+/// closest to `numeral` by edit distance, counting each inserted, deleted or substituted symbol
+/// as one edit. Numerals more than two edits away are never suggested. Suggestions that are
+/// closer in value to the lenient interpretation of `numeral` are listed first.
+///
+/// Case is ignored, and leading and trailing whitespace is trimmed. If `numeral` is already
+/// canonical, or is empty, there is nothing to suggest and the result is empty.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::suggestions;
+///
+/// assert_eq!(suggestions("XIIX")[..2], ["XVIII", "XIX"]);
+/// assert_eq!(suggestions("XIV!"), ["XIV"]);
+/// assert!(suggestions("XIV").is_empty());
+/// ```
+pub fn suggestions(numeral: &str) -> Vec<String> {
+    let numeral = numeral.trim().to_uppercase();
+    if numeral.is_empty() || is_canonical(&numeral) {
+        return Vec::new();
+    }
+    let lenient_value = roman_to_integer_lenient(&numeral).ok();
+    let len = numeral.chars().count();
+    let mut candidates: Vec<(usize, u32, String)> = (MIN_VALUE..=MAX_VALUE)
+        // The edit distance is at least the difference in length, which is cheap to compute
+        .filter(|&val| numeral_len(val).abs_diff(len) <= MAX_DISTANCE)
+        .filter_map(|val| {
+            let candidate = integer_to_roman(val).ok()?;
+            let distance = edit_distance(&numeral, &candidate);
+            let closeness =
+                lenient_value.map_or(val, |lenient| lenient.max(val) - lenient.min(val));
+            Some((distance, closeness, candidate))
+        })
+        .filter(|(distance, ..)| *distance <= MAX_DISTANCE)
+        .collect();
+    candidates.sort();
+    let nearest = candidates.first().map(|(distance, ..)| *distance);
+    let mut result: Vec<String> =
+        lenient_value.and_then(|val| integer_to_roman(val).ok()).into_iter().collect();
+    for (distance, _, candidate) in candidates {
+        if Some(distance) == nearest && !result.contains(&candidate) {
+            result.push(candidate);
+        }
+    }
+    result
+}
+
+/// The Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        normalize, roman_to_integer_with_suggestions, suggestions, ErrorWithSuggestions,
+        RomanNumeralError,
+    };
+
+    use super::edit_distance;
+
+    #[test]
+    fn check_edit_distance() {
+        assert_eq!(edit_distance("XIV", "XIV"), 0);
+        assert_eq!(edit_distance("XIIX", "XIX"), 1);
+        assert_eq!(edit_distance("XIIX", "XVIII"), 2);
+        assert_eq!(edit_distance("", "MMM"), 3);
+        assert_eq!(edit_distance("CMM", ""), 3);
+    }
+
+    #[test]
+    fn suggest_lenient_value_first() {
+        assert_eq!(suggestions("XIIX"), ["XVIII", "XIX", "XIII", "XII", "XXIX", "XLIX", "XCIX"]);
+        assert_eq!(suggestions("iiii")[0], "IV");
+        assert_eq!(suggestions(" VV ")[0], "X");
+    }
+
+    #[test]
+    fn suggest_nearest_numerals() {
+        assert_eq!(suggestions("XIVB"), ["XIV"]);
+        assert_eq!(suggestions("MCMXCIX!"), ["MCMXCIX"]);
+        assert!(suggestions("Blah").is_empty());
+    }
+
+    #[test]
+    fn no_suggestions_for_canonical_or_empty_values() {
+        for val in ["I", "xiv", " MMMCMXCIX ", "", "  "].iter() {
+            assert!(suggestions(val).is_empty(), "{}", val);
+        }
+    }
+
+    #[test]
+    fn include_suggestions_with_errors() {
+        assert_eq!(roman_to_integer_with_suggestions(" xiv "), Ok(14));
+        let error = roman_to_integer_with_suggestions("iiii").unwrap_err();
+        assert_eq!(
+            error.error(),
+            &RomanNumeralError::InvalidRepetition {
+                symbol: "I",
+                count: 4,
+                pos: crate::Position::new(3, 3)
+            }
+        );
+        assert_eq!(error.suggestions(), suggestions("iiii"));
+        assert_eq!(error.hint().unwrap(), "did you mean IV, III or VIII?");
+        let error = roman_to_integer_with_suggestions("Blah").unwrap_err();
+        assert!(error.suggestions().is_empty());
+        assert_eq!(error.hint(), None);
+        assert_eq!(error.to_string(), "'B' at position 1 is not a Roman numeral symbol");
+        assert_eq!(RomanNumeralError::from(error), crate::roman_to_integer("Blah").unwrap_err());
+    }
+
+    #[test]
+    fn add_suggestions_to_other_errors() {
+        let error = ErrorWithSuggestions::new("MMMM", normalize("MMMM").unwrap_err());
+        assert_eq!(error.into_error(), RomanNumeralError::ValueTooLarge(4000));
+        let error = ErrorWithSuggestions::new("", RomanNumeralError::EmptyString);
+        assert_eq!(error.to_string(), "no Roman numeral provided");
+    }
+}
@@ -0,0 +1,161 @@
+/// For each byte, whether it is a Roman numeral symbol, in either case.
+const IS_SYMBOL: [bool; 256] = {
+    let mut table = [false; 256];
+    let symbols = b"IVXLCDMivxlcdm";
+    let mut i = 0;
+    while i < symbols.len() {
+        table[symbols[i] as usize] = true;
+        i += 1;
+    }
+    table
+};
+
+/// The length of the longest valid numeral, `MMMDCCCLXXXVIII`.
+const MAX_NUMERAL_LEN: usize = 15;
+
+/// Checks whether every byte of `bytes` is a Roman numeral symbol, in either case.
+pub(crate) fn all_symbols(bytes: &[u8]) -> bool {
+    bytes.iter().all(|&b| IS_SYMBOL[b as usize])
+}
+
+/// Checks each of a batch of numerals for the mistakes that can be found without parsing it.
+///
+/// A numeral passes if it is no longer than the longest valid numeral, consists only of symbols,
+/// in either case, and repeats no symbol more times in a row than it may be. Numerals that pass
+/// may still be invalid, e.g. if their symbols are out of order, but those that fail are certainly
+/// invalid.
+///
+/// This is checked a numeral at a time or, with the `nightly` feature enabled, four numerals at a
+/// time using portable SIMD.
+pub(crate) fn plausible_numerals(numerals: &[&[u8]]) -> Vec<bool> {
+    #[cfg(feature = "nightly")]
+    {
+        simd::plausible_numerals(numerals)
+    }
+    #[cfg(not(feature = "nightly"))]
+    {
+        numerals.iter().map(|numeral| plausible_numeral(numeral)).collect()
+    }
+}
+
+#[cfg(any(test, not(feature = "nightly")))]
+fn plausible_numeral(numeral: &[u8]) -> bool {
+    let mut run = 0;
+    !numeral.is_empty()
+        && numeral.len() <= MAX_NUMERAL_LEN
+        && numeral.iter().enumerate().all(|(i, &b)| {
+            run = if i > 0 && b.eq_ignore_ascii_case(&numeral[i - 1]) { run + 1 } else { 1 };
+            let max_run = match b.to_ascii_uppercase() {
+                b'V' | b'L' | b'D' => 1,
+                _ => 3,
+            };
+            IS_SYMBOL[b as usize] && run <= max_run
+        })
+}
+
+#[cfg(feature = "nightly")]
+mod simd {
+    use std::simd::cmp::SimdPartialEq;
+    use std::simd::u8x64;
+
+    use super::MAX_NUMERAL_LEN;
+
+    /// The number of bytes given to each numeral in a vector, which leaves at least one byte of
+    /// padding after each numeral, so that runs of symbols never cross from one to the next.
+    const SLOT: usize = 16;
+    const SLOTS: usize = 64 / SLOT;
+
+    pub(super) fn plausible_numerals(numerals: &[&[u8]]) -> Vec<bool> {
+        let mut plausible = Vec::with_capacity(numerals.len());
+        for group in numerals.chunks(SLOTS) {
+            let mut buffer = [0; SLOTS * SLOT];
+            for (slot, numeral) in group.iter().enumerate() {
+                if numeral.len() <= MAX_NUMERAL_LEN {
+                    buffer[slot * SLOT..][..numeral.len()].copy_from_slice(numeral);
+                }
+            }
+            let invalid = invalid_bytes(u8x64::from_array(buffer));
+            plausible.extend(group.iter().enumerate().map(|(slot, numeral)| {
+                let in_slot = (1 << numeral.len().min(SLOT)) - 1;
+                (1..=MAX_NUMERAL_LEN).contains(&numeral.len())
+                    && (invalid >> (slot * SLOT)) & in_slot == 0
+            }));
+        }
+        plausible
+    }
+
+    /// A bitmask of the bytes that are not symbols, or that repeat a symbol too many times.
+    fn invalid_bytes(bytes: u8x64) -> u64 {
+        // Clearing bit 5 upper-cases ASCII letters, and can only map a byte onto an upper-case
+        // letter if it was that letter in lower-case.
+        let upper = bytes & u8x64::splat(!0x20);
+        let is = |symbol| upper.simd_eq(u8x64::splat(symbol)).to_bitmask();
+        let fives = is(b'V') | is(b'L') | is(b'D');
+        let symbols = fives | is(b'I') | is(b'X') | is(b'C') | is(b'M');
+        // Bytes that are the same symbol as the byte before them, which is always padding for the
+        // first byte of a slot
+        let repeated = symbols & upper.simd_eq(upper.rotate_elements_right::<1>()).to_bitmask();
+        // Bytes that end a run of four of the same symbol
+        let fourth = repeated & (repeated << 1) & (repeated << 2);
+        !symbols | (repeated & fives) | fourth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all_symbols, plausible_numeral, plausible_numerals};
+    use crate::integer_to_roman;
+
+    #[test]
+    fn accept_symbols_in_either_case() {
+        assert!(all_symbols(b""));
+        assert!(all_symbols(b"MCMXCIX"));
+        assert!(all_symbols(b"mmmdccclxxxviii"));
+        assert!(!all_symbols(b"XIV "));
+        assert!(!all_symbols("MMMDCCCLXXXVIIIé".as_bytes()));
+    }
+
+    #[test]
+    fn check_batches_of_numerals() {
+        let numerals: [&[u8]; 12] = [
+            b"MMMDCCCLXXXVIII",
+            b"",
+            b"xiv",
+            b"IIII",
+            b"VV",
+            b"MMMDCCCLXXXVIIII",
+            b"XIIV",
+            b"iiiI",
+            b"X I",
+            b"CCCXXXIII",
+            // Bytes that differ from a symbol only in bit 5
+            b"\x09\x16\x18\x0c\x03\x04\x0d",
+            b"MMMMMMMMMMMMMMMMMMMMM",
+        ];
+        let expected =
+            [true, false, true, false, false, false, true, false, false, true, false, false];
+        assert_eq!(plausible_numerals(&numerals), expected);
+        assert_eq!(plausible_numerals(&numerals[1..]), expected[1..]);
+        assert!(plausible_numerals(&[]).is_empty());
+    }
+
+    #[test]
+    fn agree_with_check_of_one_numeral() {
+        let mut numerals: Vec<Vec<u8>> =
+            (1..=3999).map(|val| integer_to_roman(val).unwrap().into_bytes()).collect();
+        for b in 0..=255u8 {
+            for len in [1, 2, 4, 15, 16].iter() {
+                let mut bytes = vec![b'I'; *len];
+                bytes[len - 1] = b;
+                numerals.push(bytes.clone());
+                bytes[0] = b;
+                numerals.push(bytes);
+            }
+        }
+        let numerals: Vec<&[u8]> = numerals.iter().map(Vec::as_slice).collect();
+        let expected: Vec<bool> =
+            numerals.iter().map(|numeral| plausible_numeral(numeral)).collect();
+        assert_eq!(plausible_numerals(&numerals), expected);
+        assert!(expected[..3999].iter().all(|&plausible| plausible));
+    }
+}
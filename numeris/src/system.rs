@@ -0,0 +1,141 @@
+use super::{integer_to_roman, roman_to_integer, Result, MAX_VALUE, MIN_VALUE};
+
+/// A system of numerals, which can convert integers to and from numerals.
+///
+/// This allows programs to work with any of the numeral systems supported by this crate, chosen
+/// at runtime, such as from a list offered to the user. Every system that is enabled is listed by
+/// [`numeral_systems`], and can be found by its [`id`][NumeralSystem::id] with
+/// [`numeral_system`].
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{numeral_system, NumeralSystem};
+///
+/// let roman = numeral_system("roman").unwrap();
+/// assert_eq!(roman.name(), "Roman");
+/// assert_eq!(roman.format(42).unwrap(), "XLII");
+/// assert_eq!(roman.parse("XLII").unwrap(), 42);
+/// ```
+pub trait NumeralSystem: Sync {
+    /// A short, lower-case identifier for this system, e.g. `roman`, which is suitable for
+    /// configuration files and command-line arguments.
+    fn id(&self) -> &'static str;
+
+    /// The name of this system, e.g. `Roman`, which is suitable for displaying to a user.
+    fn name(&self) -> &'static str;
+
+    /// The smallest value that can be written in this system.
+    fn min_value(&self) -> u32;
+
+    /// The largest value that can be written in this system.
+    fn max_value(&self) -> u32;
+
+    /// Converts an integer into a string representing a numeral in this system.
+    fn format(&self, val: u32) -> Result<String>;
+
+    /// Converts a string representing a numeral in this system into an integer.
+    fn parse(&self, numeral: &str) -> Result<u32>;
+}
+
+/// Roman numerals, written in their canonical form by [`integer_to_roman`] and parsed by
+/// [`roman_to_integer`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Roman;
+
+impl NumeralSystem for Roman {
+    fn id(&self) -> &'static str {
+        "roman"
+    }
+
+    fn name(&self) -> &'static str {
+        "Roman"
+    }
+
+    fn min_value(&self) -> u32 {
+        MIN_VALUE
+    }
+
+    fn max_value(&self) -> u32 {
+        MAX_VALUE
+    }
+
+    fn format(&self, val: u32) -> Result<String> {
+        integer_to_roman(val)
+    }
+
+    fn parse(&self, numeral: &str) -> Result<u32> {
+        roman_to_integer(numeral)
+    }
+}
+
+/// The systems listed by [`numeral_systems`].
+static SYSTEMS: &[&dyn NumeralSystem] = &[
+    &Roman,
+    #[cfg(feature = "greek")]
+    &crate::greek::Greek,
+    #[cfg(feature = "attic")]
+    &crate::attic::Attic,
+];
+
+/// All of the numeral systems that are enabled, with Roman numerals first.
+///
+/// [`Roman`] numerals are always available. Greek numerals are available with the `greek`
+/// feature, and Attic numerals with the `attic` feature.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::numeral_systems;
+///
+/// for system in numeral_systems() {
+///     println!("{}: {}", system.name(), system.format(12).unwrap());
+/// }
+/// ```
+pub fn numeral_systems() -> &'static [&'static dyn NumeralSystem] {
+    SYSTEMS
+}
+
+/// The enabled numeral system with the given [`id`][NumeralSystem::id], ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::numeral_system;
+///
+/// assert_eq!(numeral_system("Roman").unwrap().format(4).unwrap(), "IV");
+/// assert!(numeral_system("mayan").is_none());
+/// ```
+pub fn numeral_system(id: &str) -> Option<&'static dyn NumeralSystem> {
+    SYSTEMS.iter().copied().find(|system| system.id().eq_ignore_ascii_case(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{numeral_system, numeral_systems};
+    use crate::RomanNumeralError;
+
+    #[test]
+    fn find_systems_by_id() {
+        assert_eq!(numeral_system("roman").unwrap().id(), "roman");
+        assert_eq!(numeral_system("ROMAN").unwrap().name(), "Roman");
+        assert!(numeral_system("").is_none());
+        #[cfg(feature = "greek")]
+        assert_eq!(numeral_system("greek").unwrap().format(12).unwrap(), "ιβʹ");
+        #[cfg(feature = "attic")]
+        assert_eq!(numeral_system("attic").unwrap().format(12).unwrap(), "ΔΙΙ");
+    }
+
+    #[test]
+    fn round_trip_every_system() {
+        for system in numeral_systems() {
+            assert_eq!(numeral_system(system.id()).map(|found| found.id()), Some(system.id()));
+            for val in system.min_value()..=system.max_value() {
+                let numeral = system.format(val).unwrap();
+                assert_eq!(system.parse(&numeral), Ok(val), "{}: {}", system.id(), numeral);
+            }
+            let too_large = system.max_value() + 1;
+            assert_eq!(system.format(too_large), Err(RomanNumeralError::ValueTooLarge(too_large)));
+        }
+    }
+}
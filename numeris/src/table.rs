@@ -0,0 +1,162 @@
+use std::iter::FusedIterator;
+use std::ops::{Bound, RangeBounds};
+
+use super::spreadsheet::build_spreadsheet_roman;
+use super::{
+    integer_to_roman_unchecked, Result, RomanNumeral, RomanRange, SpreadsheetForm, MAX_VALUE,
+    MIN_VALUE,
+};
+
+/// Lists each value in `range` alongside its Roman numeral, e.g. for reference tables, quiz banks
+/// or documentation.
+///
+/// The range may be of any kind, e.g. `1..=10`, `..100` or `2000..`. Missing bounds are taken to
+/// be [`MIN_VALUE`] and [`MAX_VALUE`]. The bounds that are given must be in range, whether or not
+/// the range is empty, except that an excluded end may be one past [`MAX_VALUE`]. The numerals are
+/// canonical and upper-case, unless the style of the table is changed with
+/// [`RomanTable::lowercase`] or [`RomanTable::form`].
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{roman_table, SpreadsheetForm};
+///
+/// let table: Vec<(u32, String)> = roman_table(1..=3).unwrap().collect();
+/// assert_eq!(table, [(1, "I".into()), (2, "II".into()), (3, "III".into())]);
+/// let mut table = roman_table(..).unwrap().lowercase().form(SpreadsheetForm::Simplified);
+/// assert_eq!(table.len(), 3999);
+/// assert_eq!(table.nth(498).unwrap(), (499, "id".into()));
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`RomanNumeral::new`][crate::RomanNumeral::new] for the first value of
+/// the range, or for its end.
+pub fn roman_table<R: RangeBounds<u32>>(range: R) -> Result<RomanTable> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => MIN_VALUE,
+    };
+    RomanNumeral::new(start)?;
+    let end = match range.end_bound() {
+        Bound::Included(&end) => RomanNumeral::new(end)?.value(),
+        Bound::Excluded(&end) if end == MAX_VALUE + 1 => MAX_VALUE,
+        Bound::Excluded(&end) => RomanNumeral::new(end)?.value() - 1,
+        Bound::Unbounded => MAX_VALUE,
+    };
+    let range = if start <= end {
+        RomanRange::new(start, end)?
+    } else {
+        RomanRange::new(MAX_VALUE, MIN_VALUE)?
+    };
+    Ok(RomanTable { range, lowercase: false, form: SpreadsheetForm::Classic })
+}
+
+/// An iterator over a table of values and their Roman numerals, in order of value.
+///
+/// This is created by [`roman_table`]. Each item is a value with its numeral, which is only
+/// written when the item is reached.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RomanTable {
+    range: RomanRange,
+    lowercase: bool,
+    form: SpreadsheetForm,
+}
+
+impl RomanTable {
+    /// Writes the numerals in lower-case, e.g. `xlii`.
+    pub fn lowercase(self) -> Self {
+        RomanTable { lowercase: true, ..self }
+    }
+
+    /// Writes the numerals in one of the forms of the spreadsheet `ROMAN` function, as by
+    /// [`integer_to_spreadsheet_roman`][crate::integer_to_spreadsheet_roman].
+    pub fn form(self, form: SpreadsheetForm) -> Self {
+        RomanTable { form, ..self }
+    }
+
+    fn row(&self, val: u32) -> (u32, String) {
+        let mut numeral = match self.form {
+            SpreadsheetForm::Classic => integer_to_roman_unchecked(val),
+            form => build_spreadsheet_roman(val, form),
+        };
+        if self.lowercase {
+            numeral.make_ascii_lowercase();
+        }
+        (val, numeral)
+    }
+}
+
+impl Iterator for RomanTable {
+    type Item = (u32, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|rn| self.row(rn.value()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for RomanTable {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|rn| self.row(rn.value()))
+    }
+}
+
+impl ExactSizeIterator for RomanTable {}
+
+impl FusedIterator for RomanTable {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{integer_to_roman, roman_table, RomanNumeralError, SpreadsheetForm};
+
+    fn values<R: std::ops::RangeBounds<u32>>(range: R) -> Vec<u32> {
+        roman_table(range).unwrap().map(|(val, _)| val).collect()
+    }
+
+    #[test]
+    fn list_values_with_numerals() {
+        for (val, numeral) in roman_table(..).unwrap() {
+            assert_eq!(numeral, integer_to_roman(val).unwrap());
+        }
+        let table: Vec<(u32, String)> = roman_table(8..11).unwrap().rev().collect();
+        assert_eq!(table, [(10, "X".into()), (9, "IX".into()), (8, "VIII".into())]);
+    }
+
+    #[test]
+    fn accept_any_kind_of_range() {
+        assert_eq!(values(1..=3), [1, 2, 3]);
+        assert_eq!(values(..4), [1, 2, 3]);
+        assert_eq!(values(3997..), [3997, 3998, 3999]);
+        assert_eq!(roman_table(..).unwrap().len(), 3999);
+        let (start, end) = (10, 9);
+        assert!(values(start..=end).is_empty());
+        assert!(values(1..1).is_empty() && values(..1).is_empty());
+        assert_eq!(values(3999..4000), [3999]);
+    }
+
+    #[test]
+    fn reject_ranges_out_of_bounds() {
+        assert_eq!(roman_table(0..10).unwrap_err(), RomanNumeralError::ValueTooSmall(0));
+        assert_eq!(roman_table(1..=4000).unwrap_err(), RomanNumeralError::ValueTooLarge(4000));
+        assert_eq!(roman_table(0..0).unwrap_err(), RomanNumeralError::ValueTooSmall(0));
+        assert_eq!(roman_table(4000..).unwrap_err(), RomanNumeralError::ValueTooLarge(4000));
+        assert_eq!(roman_table(4000..=4000).unwrap_err(), RomanNumeralError::ValueTooLarge(4000));
+        assert_eq!(roman_table(1..4001).unwrap_err(), RomanNumeralError::ValueTooLarge(4001));
+        let (start, end) = (4000, 3999);
+        assert_eq!(roman_table(start..=end).unwrap_err(), RomanNumeralError::ValueTooLarge(4000));
+    }
+
+    #[test]
+    fn change_the_style_of_the_numerals() {
+        let table = roman_table(449..=450).unwrap().lowercase();
+        assert_eq!(table.map(|(_, rn)| rn).collect::<Vec<_>>(), ["cdxlix", "cdl"]);
+        let table = roman_table(449..=450).unwrap().form(SpreadsheetForm::Simplified);
+        assert_eq!(table.map(|(_, rn)| rn).collect::<Vec<_>>(), ["CDIL", "LD"]);
+    }
+}
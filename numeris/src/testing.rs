@@ -0,0 +1,162 @@
+//! `proptest` strategies for generating values and numerals. Requires the `proptest` feature.
+//!
+//! These cover the inputs that a program working with Roman numerals usually needs to test: every
+//! valid value, as an integer, a [`RomanNumeral`] or a canonical numeral, numerals in the
+//! non-canonical forms accepted by [`roman_to_integer_lenient`][crate::roman_to_integer_lenient],
+//! and strings that are almost, but not quite, valid numerals.
+//!
+//! # Examples
+//!
+//! ```
+//! use numeris::testing::{canonical_numerals, near_misses};
+//! use numeris::{integer_to_roman, roman_to_integer};
+//! use proptest::proptest;
+//!
+//! proptest!(|(numeral in canonical_numerals())| {
+//!     let value = roman_to_integer(&numeral).unwrap();
+//!     assert_eq!(integer_to_roman(value).unwrap(), numeral);
+//! });
+//! proptest!(|(numeral in near_misses())| {
+//!     assert!(roman_to_integer(&numeral).is_err());
+//! });
+//! ```
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::{select, Index};
+
+use super::{integer_to_roman, roman_to_integer, RomanNumeral, MAX_VALUE, MIN_VALUE};
+
+/// The symbols inserted or substituted by [`near_misses`].
+const SYMBOLS: [char; 7] = ['I', 'V', 'X', 'L', 'C', 'D', 'M'];
+
+/// The one, five and ten symbols for each decimal place, from the ones up.
+const PLACES: [(char, char, char); 3] = [('I', 'V', 'X'), ('X', 'L', 'C'), ('C', 'D', 'M')];
+
+/// The whitespace that [`lenient_numerals`] may surround a numeral with.
+const PADDING: [&str; 4] = ["", " ", "\t", " \n"];
+
+/// The longest numeral generated by [`lenient_numerals`], `MMMDCCCCLXXXXVIIII`.
+const MAX_LENIENT_LEN: usize = 18;
+
+/// Every valid value, from [`MIN_VALUE`] to [`MAX_VALUE`].
+pub fn values() -> impl Strategy<Value = u32> {
+    MIN_VALUE..=MAX_VALUE
+}
+
+/// Every valid [`RomanNumeral`].
+pub fn numerals() -> impl Strategy<Value = RomanNumeral> {
+    values().prop_map(|val| RomanNumeral::new(val).expect("generated a valid value"))
+}
+
+/// Every valid numeral, in its canonical, upper-case, form, as returned by [`integer_to_roman`].
+pub fn canonical_numerals() -> impl Strategy<Value = String> {
+    values().prop_map(|val| integer_to_roman(val).expect("generated a valid value"))
+}
+
+/// Numerals accepted by [`roman_to_integer_lenient`][crate::roman_to_integer_lenient], with
+/// their values.
+///
+/// Each 4 or 9 may be written additively (e.g. `IIII` or `VIIII`) rather than subtractively,
+/// symbols may be in either case, and the numeral may be surrounded by whitespace. As these are
+/// chosen independently, some of the numerals are also accepted by [`roman_to_integer`].
+pub fn lenient_numerals() -> impl Strategy<Value = (String, u32)> {
+    let padding = (select(&PADDING[..]), select(&PADDING[..]));
+    (values(), any::<[bool; 3]>(), vec(any::<bool>(), MAX_LENIENT_LEN), padding).prop_map(
+        |(val, additive, lower, (before, after))| {
+            let mut numeral = "M".repeat((val / 1000) as usize);
+            for (place, &(one, five, ten)) in PLACES.iter().enumerate().rev() {
+                let digit = val / 10u32.pow(place as u32) % 10;
+                numeral.push_str(&write_digit(digit, additive[place], one, five, ten));
+            }
+            let numeral: String = numeral
+                .chars()
+                .zip(lower)
+                .map(|(ch, lower)| if lower { ch.to_ascii_lowercase() } else { ch })
+                .collect();
+            (format!("{}{}{}", before, numeral, after), val)
+        },
+    )
+}
+
+/// Strings that are one edit away from a canonical numeral, but are not valid numerals.
+///
+/// Each one is made by inserting, deleting or substituting a single symbol in a canonical
+/// numeral, or by swapping two adjacent symbols, e.g. `XIIV`, `CMM` or `IIX`. These are the
+/// mistakes that people most often make, so they exercise the error handling of a parser more
+/// thoroughly than random strings do. Every one is rejected by [`roman_to_integer`], though some
+/// are accepted by [`roman_to_integer_lenient`][crate::roman_to_integer_lenient].
+pub fn near_misses() -> impl Strategy<Value = String> {
+    (canonical_numerals(), 0..4u8, any::<Index>(), select(&SYMBOLS[..]))
+        .prop_map(|(numeral, edit, index, symbol)| {
+            let mut symbols: Vec<char> = numeral.chars().collect();
+            let len = symbols.len();
+            match edit {
+                0 => symbols.insert(index.index(len + 1), symbol),
+                1 => {
+                    symbols.remove(index.index(len));
+                }
+                2 => symbols[index.index(len)] = symbol,
+                _ if len > 1 => {
+                    let i = index.index(len - 1);
+                    symbols.swap(i, i + 1);
+                }
+                _ => symbols.push(symbols[0]),
+            }
+            symbols.into_iter().collect::<String>()
+        })
+        .prop_filter("near misses must be invalid", |numeral| {
+            roman_to_integer(numeral).is_err() && !numeral.is_empty()
+        })
+}
+
+fn write_digit(digit: u32, additive: bool, one: char, five: char, ten: char) -> String {
+    let ones = |count: u32| one.to_string().repeat(count as usize);
+    match digit {
+        4 if !additive => format!("{}{}", one, five),
+        9 if !additive => format!("{}{}", one, ten),
+        0..=4 => ones(digit),
+        _ => format!("{}{}", five, ones(digit - 5)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{classify, roman_to_integer_lenient, NumeralForm};
+
+    proptest! {
+        #[test]
+        fn generate_valid_numerals(
+            val in values(),
+            rn in numerals(),
+            numeral in canonical_numerals(),
+        ) {
+            prop_assert!(RomanNumeral::new(val).is_ok());
+            prop_assert_eq!(integer_to_roman(rn.value()).unwrap(), rn.to_string());
+            prop_assert_eq!(classify(&numeral), NumeralForm::Canonical);
+        }
+
+        #[test]
+        fn generate_lenient_numerals((numeral, val) in lenient_numerals()) {
+            prop_assert_eq!(roman_to_integer_lenient(&numeral).unwrap(), val);
+        }
+
+        #[test]
+        fn generate_near_misses(numeral in near_misses()) {
+            prop_assert!(roman_to_integer(&numeral).is_err());
+            prop_assert!(numeral.chars().all(|ch| SYMBOLS.contains(&ch)));
+        }
+    }
+
+    #[test]
+    fn write_digits() {
+        let digits: Vec<String> =
+            (0..10).map(|digit| write_digit(digit, false, 'I', 'V', 'X')).collect();
+        assert_eq!(digits, ["", "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"]);
+        assert_eq!(write_digit(4, true, 'X', 'L', 'C'), "XXXX");
+        assert_eq!(write_digit(9, true, 'C', 'D', 'M'), "DCCCC");
+    }
+}
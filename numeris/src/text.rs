@@ -0,0 +1,426 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use super::{integer_to_roman, roman_to_integer};
+
+/// Words after which a numeral is always taken to be a number, e.g. `Chapter XII`, in lower-case.
+const KEYWORDS: [&str; 18] = [
+    "act", "appendix", "article", "book", "canto", "chapter", "class", "no", "number", "part",
+    "phase", "psalm", "scene", "section", "stage", "type", "volume", "year",
+];
+
+/// Numerals that are also common words, abbreviations or initials, e.g. `I`, `CD` or `DC`, which
+/// are only taken to be numbers after one of the [`KEYWORDS`].
+const LETTER_LIKE: [&str; 17] = [
+    "C", "CC", "CD", "CI", "CIV", "CM", "CV", "DC", "DI", "DIV", "I", "LI", "MC", "MD", "MI",
+    "MIX", "MM",
+];
+
+/// Which of the numerals in a text are replaced by [`arabize_text_with`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum NumeralContext {
+    /// Only numerals that directly follow a numbering keyword, such as `Chapter` or `Psalm`, or a
+    /// capitalised name, such as `Henry` or `Bowl`, are replaced. Upper-case words such as `MIX`,
+    /// `CD` and `I` are common in ordinary prose, so this is used by [`arabize_text`].
+    ///
+    /// A capitalised word is only taken to be a name if it is not the first word of a sentence,
+    /// so `King Henry VIII` is replaced but `Then VIII` is not. Numerals that are also common
+    /// words or abbreviations, such as `I`, `CD`, `DC` and `MIX`, are never replaced after a
+    /// name, only after a keyword, so `Chapter I` is replaced but `Washington DC` is not.
+    #[default]
+    Required,
+    /// Every numeral found by [`scan`] is replaced, wherever it appears.
+    Ignored,
+}
+
+/// A Roman numeral found in a larger text by [`scan`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RomanMatch<'a> {
+    numeral: &'a str,
+    start: usize,
+    value: u32,
+}
+
+impl<'a> RomanMatch<'a> {
+    /// The numeral, as it appears in the text.
+    pub fn as_str(&self) -> &'a str {
+        self.numeral
+    }
+
+    /// The value of the numeral.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// The byte offset in the text at which the numeral starts.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The byte offset in the text just after the end of the numeral.
+    pub fn end(&self) -> usize {
+        self.start + self.numeral.len()
+    }
+
+    /// The range of bytes in the text occupied by the numeral.
+    pub fn range(&self) -> Range<usize> {
+        self.start()..self.end()
+    }
+}
+
+/// Finds all of the Roman numerals in a text.
+///
+/// The text is split into words, which are runs of letters, digits and underscores, and each word
+/// that is a valid, upper-case Roman numeral is matched. Numerals that are only part of a word are
+/// not matched, so e.g. `MIX` is not found in `MIXER`, and neither is anything in a word that is
+/// not a valid numeral, such as `MIXI`. Lower-case numerals are ignored, as they are more likely to
+/// be ordinary words (e.g. `mix` or `did`) than numbers. Note that a capital `I` on its own is
+/// always matched as 1.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::scan;
+///
+/// let text = "Louis XIV's reign (MDCXLIII-MDCCXV) followed the MIXER.";
+/// let values: Vec<u32> = scan(text).map(|rn| rn.value()).collect();
+/// assert_eq!(values, [14, 1643, 1715]);
+/// let first = scan(text).next().unwrap();
+/// assert_eq!((first.as_str(), first.range()), ("XIV", 6..9));
+/// ```
+pub fn scan(text: &str) -> RomanMatches<'_> {
+    RomanMatches { words: Words::new(text) }
+}
+
+/// An iterator over the Roman numerals in a text.
+///
+/// This is created by [`scan`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RomanMatches<'a> {
+    words: Words<'a>,
+}
+
+impl<'a> Iterator for RomanMatches<'a> {
+    type Item = RomanMatch<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.words.by_ref().find_map(|(start, word)| {
+            if !word.bytes().all(|b| b.is_ascii_uppercase()) {
+                return None;
+            }
+            let value = roman_to_integer(word).ok()?;
+            Some(RomanMatch { numeral: word, start, value })
+        })
+    }
+}
+
+impl FusedIterator for RomanMatches<'_> {}
+
+/// Replaces every standalone integer in a text with its Roman numeral.
+///
+/// Integers are found in the same way as [`scan`] finds numerals: only words made up entirely of
+/// decimal digits are replaced, so e.g. `A4` and `4th` are left as they are. Digits that are part
+/// of a larger number, such as the parts of `3.14` or `1,000`, are also left as they are, as are
+/// integers that cannot be represented as Roman numerals, and integers with leading zeros (e.g.
+/// `007`). Everything else in the text is preserved.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::romanize_text;
+///
+/// assert_eq!(romanize_text("Chapter 42, page 3.5"), "Chapter XLII, page 3.5");
+/// assert_eq!(romanize_text("Super Bowl 50 (2016)"), "Super Bowl L (MMXVI)");
+/// assert_eq!(romanize_text("4000 A4 sheets"), "4000 A4 sheets");
+/// ```
+pub fn romanize_text(text: &str) -> String {
+    let replacements = Words::new(text).filter_map(|(start, word)| {
+        if word.starts_with('0') || !word.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        if is_part_of_number(&text[..start], &text[start + word.len()..]) {
+            return None;
+        }
+        let numeral = integer_to_roman(word.parse::<u32>().ok()?).ok()?;
+        Some((start..start + word.len(), numeral))
+    });
+    replace_ranges(text, replacements)
+}
+
+/// Replaces the Roman numerals in a text that are used as numbers with their values, as decimal
+/// integers.
+///
+/// Numerals are found as by [`scan`], so only upper-case numerals that are whole words are
+/// considered, and are only replaced in a numeric context, as described by
+/// [`NumeralContext::Required`]. Everything else in the text is preserved. To replace every
+/// numeral, use [`arabize_text_with`].
+///
+/// # Examples
+///
+/// ```
+/// use numeris::arabize_text;
+///
+/// assert_eq!(arabize_text("Chapter XLII: Louis XIV"), "Chapter 42: Louis 14");
+/// assert_eq!(arabize_text("I went to MIX a CD"), "I went to MIX a CD");
+/// assert_eq!(arabize_text("MIXER and mix"), "MIXER and mix");
+/// ```
+pub fn arabize_text(text: &str) -> String {
+    arabize_text_with(text, NumeralContext::Required)
+}
+
+/// Replaces the Roman numerals in a text with their values, as decimal integers, choosing which
+/// to replace by their context.
+///
+/// This behaves like [`arabize_text`], except that `context` determines which of the numerals
+/// found by [`scan`] are replaced.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::{arabize_text_with, NumeralContext};
+///
+/// let text = "Queen Elizabeth II (MCMXXVI-MMXXII)";
+/// let required = arabize_text_with(text, NumeralContext::Required);
+/// assert_eq!(required, "Queen Elizabeth 2 (MCMXXVI-MMXXII)");
+/// let ignored = arabize_text_with(text, NumeralContext::Ignored);
+/// assert_eq!(ignored, "Queen Elizabeth 2 (1926-2022)");
+/// ```
+pub fn arabize_text_with(text: &str, context: NumeralContext) -> String {
+    arabize_after("", text, context)
+}
+
+/// Replaces the numerals in `text`, which follows `before` in some larger text, using `before`
+/// only as the context of the numerals.
+pub(crate) fn arabize_after(before: &str, text: &str, context: NumeralContext) -> String {
+    let whole = format!("{}{}", before, text);
+    let replacements = scan(&whole)
+        .filter(|rn| rn.start() >= before.len())
+        .filter(|rn| {
+            context == NumeralContext::Ignored || is_numeric_context(&whole[..rn.start()], rn)
+        })
+        .map(|rn| (rn.start() - before.len()..rn.end() - before.len(), rn.value().to_string()));
+    replace_ranges(text, replacements)
+}
+
+/// Whether `rn`, which follows `before`, directly follows a numbering keyword or, unless it is
+/// [letter-like][LETTER_LIKE], a name.
+fn is_numeric_context(before: &str, rn: &RomanMatch) -> bool {
+    let rest = before.trim_end();
+    if rest.len() == before.len() {
+        return false;
+    }
+    let word_start = rest.trim_end_matches(is_word_char).len();
+    let word = &rest[word_start..];
+    if KEYWORDS.contains(&word.to_lowercase().as_str()) {
+        return true;
+    }
+    let mut chars = word.chars();
+    let is_name = chars.next().is_some_and(char::is_uppercase)
+        && !chars.as_str().is_empty()
+        && chars.all(char::is_lowercase);
+    is_name && !LETTER_LIKE.contains(&rn.as_str()) && !starts_sentence(&rest[..word_start])
+}
+
+/// Whether a word that follows `before` is the first word of a sentence.
+fn starts_sentence(before: &str) -> bool {
+    let before = before.trim_end();
+    before.is_empty() || before.ends_with(is_sentence_break)
+}
+
+/// Whether a word directly after `ch` starts a new sentence, or a quotation or aside.
+fn is_sentence_break(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?' | '"' | '\'' | '“' | '‘' | '”' | '’' | '(' | '[' | '«' | '»')
+}
+
+/// The end of `text` that is needed as the context of a numeral that follows it: its last word,
+/// with everything after it and the whitespace and character before it.
+pub(crate) fn context_of(text: &str) -> &str {
+    let rest = text.trim_end_matches(|ch| !is_word_char(ch));
+    let rest = rest.trim_end_matches(is_word_char).trim_end();
+    let start = rest.chars().next_back().map_or(0, |ch| rest.len() - ch.len_utf8());
+    &text[start..]
+}
+
+/// Whether the digits between `before` and `after` are part of a larger number, i.e. they are
+/// separated from other digits by a single decimal point or thousands separator.
+fn is_part_of_number(before: &str, after: &str) -> bool {
+    let is_separator = |ch: Option<char>| matches!(ch, Some('.') | Some(','));
+    let is_digit = |ch: Option<char>| ch.is_some_and(|ch| ch.is_ascii_digit());
+    let mut before = before.chars().rev();
+    let mut after = after.chars();
+    (is_separator(before.next()) && is_digit(before.next()))
+        || (is_separator(after.next()) && is_digit(after.next()))
+}
+
+/// Copies `text`, replacing each of the given ranges, which must be in order and must not
+/// overlap, with its replacement.
+fn replace_ranges<I>(text: &str, replacements: I) -> String
+where
+    I: Iterator<Item = (Range<usize>, String)>,
+{
+    let mut result = String::with_capacity(text.len());
+    let mut copied = 0;
+    for (range, replacement) in replacements {
+        result.push_str(&text[copied..range.start]);
+        result.push_str(&replacement);
+        copied = range.end;
+    }
+    result.push_str(&text[copied..]);
+    result
+}
+
+pub(crate) fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+/// An iterator over the words in a text, with the byte offset at which each one starts.
+#[derive(Clone, Debug)]
+struct Words<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Words<'a> {
+    fn new(text: &'a str) -> Self {
+        Words { text, pos: 0 }
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.text[self.pos..];
+        let start = self.pos + rest.find(is_word_char)?;
+        let word = &self.text[start..];
+        let word = &word[..word.find(|ch| !is_word_char(ch)).unwrap_or(word.len())];
+        self.pos = start + word.len();
+        Some((start, word))
+    }
+}
+
+impl FusedIterator for Words<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{arabize_text, arabize_text_with, romanize_text, scan, NumeralContext};
+
+    use super::context_of;
+
+    fn found(text: &str) -> Vec<(&str, usize, u32)> {
+        scan(text).map(|rn| (rn.as_str(), rn.start(), rn.value())).collect()
+    }
+
+    #[test]
+    fn find_numerals_between_words() {
+        assert_eq!(found("Chapter XLII"), [("XLII", 8, 42)]);
+        assert_eq!(found("XIV"), [("XIV", 0, 14)]);
+        assert_eq!(
+            found("Henry VIII, Edward VI and Elizabeth I."),
+            [("VIII", 6, 8), ("VI", 19, 6), ("I", 36, 1)]
+        );
+        assert_eq!(found("«MMXX»—«MMXXI»"), [("MMXX", 2, 2020), ("MMXXI", 13, 2021)]);
+    }
+
+    #[test]
+    fn ignore_numerals_within_words() {
+        assert!(found("MIXER CIVIC LIVID DIVX_ CD4 X2 _X").is_empty());
+        assert!(found("Éxiv XIVé").is_empty());
+    }
+
+    #[test]
+    fn ignore_invalid_and_lower_case_numerals() {
+        assert!(found("IIII VV MMMM IC").is_empty());
+        assert!(found("mix civic did").is_empty());
+        assert!(found("").is_empty());
+        assert!(found(" \n\t. ").is_empty());
+    }
+
+    #[test]
+    fn report_byte_ranges() {
+        let text = "Ünïcödé XII";
+        let rn = scan(text).next().unwrap();
+        assert_eq!(&text[rn.range()], "XII");
+        assert_eq!(rn.end(), text.len());
+    }
+
+    #[test]
+    fn romanize_standalone_integers() {
+        assert_eq!(romanize_text("1 2 3"), "I II III");
+        assert_eq!(romanize_text("Psalm 23:4"), "Psalm XXIII:IV");
+        assert_eq!(romanize_text("(1999-2001)"), "(MCMXCIX-MMI)");
+        assert_eq!(romanize_text("Ünïcödé 12!"), "Ünïcödé XII!");
+        assert_eq!(romanize_text("3999"), "MMMCMXCIX");
+        assert_eq!(romanize_text(""), "");
+    }
+
+    #[test]
+    fn preserve_other_numbers() {
+        let values = ["0", "4000", "007", "3.14", "1,000", "v1.2", "2nd", "x86_64", "99999999999"];
+        for val in values.iter() {
+            assert_eq!(romanize_text(val), *val);
+        }
+        assert_eq!(romanize_text("1. 2, 3"), "I. II, III");
+    }
+
+    #[test]
+    fn arabize_numerals() {
+        assert_eq!(arabize_text("King Henry VIII, Edward VI"), "King Henry 8, Edward 6");
+        assert_eq!(arabize_text("Year MMXX MIXER xiv IIII"), "Year 2020 MIXER xiv IIII");
+        assert_eq!(arabize_text("CHAPTER IV. Psalm\tXXIII"), "CHAPTER 4. Psalm\t23");
+        assert_eq!(arabize_text("World War II and Super Bowl L"), "World War 2 and Super Bowl 50");
+        assert_eq!(
+            arabize_text("Under Louis XIV. Book I, Chapter CD"),
+            "Under Louis 14. Book 1, Chapter 400"
+        );
+        assert_eq!(
+            arabize_text("By Henry VIII, Edward VI and Elizabeth II."),
+            "By Henry 8, Edward 6 and Elizabeth 2."
+        );
+    }
+
+    #[test]
+    fn leave_numerals_in_prose() {
+        let prose = [
+            "I went home. Then I slept, and I MIX the DC CD",
+            "So I said \"Then I left\"",
+            "MMXX «MMXX» (Henry)VIII Henry, VIII Henry-VIII the VI",
+            "MD",
+            "The CD player",
+            "Washington DC is nice",
+            "Visit DC today",
+            "Ask Mr Smith I said",
+            "Then Elizabeth I left. Henry VIII arrived",
+            "Queen Elizabeth I and Mr Smith CD",
+            "",
+        ];
+        for text in prose.iter() {
+            assert_eq!(arabize_text(text), *text);
+        }
+    }
+
+    #[test]
+    fn arabize_numerals_in_any_context() {
+        let text = "I MIX the DC CD «MMXX» xiv IIII";
+        assert_eq!(
+            arabize_text_with(text, NumeralContext::Ignored),
+            "1 1009 the 600 400 «2020» xiv IIII"
+        );
+    }
+
+    #[test]
+    fn keep_enough_context() {
+        assert_eq!(context_of("Books by Elizabeth, "), "y Elizabeth, ");
+        assert_eq!(context_of("Elizabeth "), "Elizabeth ");
+        assert_eq!(context_of("end. \"Then "), "\"Then ");
+        assert_eq!(context_of(""), "");
+    }
+
+    #[test]
+    fn round_trip_text() {
+        let text = "In 1066, 7000 men fought for 9 hours; see note 14.";
+        assert_eq!(romanize_text(text), "In MLXVI, 7000 men fought for IX hours; see note XIV.");
+        assert_eq!(arabize_text_with(&romanize_text(text), NumeralContext::Ignored), text);
+    }
+}
@@ -0,0 +1,85 @@
+use super::{integer_to_roman, roman_to_integer, Result};
+
+/// Extension trait for converting integers into Roman numerals.
+///
+/// This is implemented for all of the primitive integer types, and behaves exactly like
+/// [`integer_to_roman`].
+///
+/// # Examples
+///
+/// ```
+/// use numeris::prelude::*;
+///
+/// assert_eq!(42.to_roman().unwrap(), "XLII");
+/// assert_eq!(1142u64.to_roman().unwrap(), "MCXLII");
+/// assert!(0.to_roman().is_err());
+/// ```
+pub trait ToRoman {
+    /// Converts this value into a string representing a Roman numeral.
+    ///
+    /// See [`integer_to_roman`] for details and possible errors.
+    fn to_roman(&self) -> Result<String>;
+}
+
+/// Extension trait for converting Roman numerals into integers.
+///
+/// This is implemented for `str`, and behaves exactly like [`roman_to_integer`].
+///
+/// # Examples
+///
+/// ```
+/// use numeris::prelude::*;
+///
+/// assert_eq!("XLII".from_roman().unwrap(), 42);
+/// assert_eq!(String::from(" mcxlii ").from_roman().unwrap(), 1142);
+/// assert!("BAD".from_roman().is_err());
+/// ```
+pub trait FromRoman {
+    /// Converts this string, representing a Roman numeral, into an integer.
+    ///
+    /// See [`roman_to_integer`] for details and possible errors.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_roman(&self) -> Result<u32>;
+}
+
+macro_rules! impl_to_roman {
+    ($($t:ty),*) => {
+        $(
+            impl ToRoman for $t {
+                fn to_roman(&self) -> Result<String> {
+                    integer_to_roman(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_roman!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl FromRoman for str {
+    fn from_roman(&self) -> Result<u32> {
+        roman_to_integer(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::RomanNumeralError;
+
+    #[test]
+    fn integers_to_roman() {
+        assert_eq!(4u8.to_roman().unwrap(), String::from("IV"));
+        assert_eq!(90i16.to_roman().unwrap(), String::from("XC"));
+        assert_eq!(2468usize.to_roman().unwrap(), String::from("MMCDLXVIII"));
+        assert_eq!((-1i64).to_roman(), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(4000u32.to_roman(), Err(RomanNumeralError::ValueTooLarge(4000)));
+    }
+
+    #[test]
+    fn strings_from_roman() {
+        assert_eq!("IV".from_roman().unwrap(), 4);
+        assert_eq!(String::from("mmcdlxviii").from_roman().unwrap(), 2468);
+        assert_eq!("  ".from_roman(), Err(RomanNumeralError::EmptyString));
+    }
+}
@@ -0,0 +1,265 @@
+#[cfg(feature = "unicode")]
+use unicode_normalization::UnicodeNormalization;
+
+use super::{roman_to_integer, Position, Result, RomanNumeralError};
+
+/// Characters from other scripts that look like Roman numeral symbols, with the symbols that they
+/// are mistaken for.
+const CONFUSABLES: [(char, char); 17] = [
+    ('\u{0399}', 'I'), // Greek capital iota
+    ('\u{0406}', 'I'), // Cyrillic capital Byelorussian-Ukrainian i
+    ('\u{04C0}', 'I'), // Cyrillic letter palochka
+    ('\u{0456}', 'i'), // Cyrillic small Byelorussian-Ukrainian i
+    ('\u{0474}', 'V'), // Cyrillic capital izhitsa
+    ('\u{0475}', 'v'), // Cyrillic small izhitsa
+    ('\u{03BD}', 'v'), // Greek small nu
+    ('\u{03A7}', 'X'), // Greek capital chi
+    ('\u{0425}', 'X'), // Cyrillic capital ha
+    ('\u{0445}', 'x'), // Cyrillic small ha
+    ('\u{00D7}', 'x'), // Multiplication sign
+    ('\u{03F9}', 'C'), // Greek capital lunate sigma
+    ('\u{0421}', 'C'), // Cyrillic capital es
+    ('\u{03F2}', 'c'), // Greek small lunate sigma
+    ('\u{0441}', 'c'), // Cyrillic small es
+    ('\u{039C}', 'M'), // Greek capital mu
+    ('\u{041C}', 'M'), // Cyrillic capital em
+];
+
+/// A character that was replaced by the Roman numeral symbol that it looks like, as reported by
+/// [`roman_to_integer_confusable`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Substitution {
+    found: char,
+    replacement: char,
+    pos: Position,
+}
+
+impl Substitution {
+    /// The character that was found, e.g. the Cyrillic `Х`.
+    pub fn found(&self) -> char {
+        self.found
+    }
+
+    /// The symbol that it was replaced with, e.g. the Latin `X`.
+    pub fn replacement(&self) -> char {
+        self.replacement
+    }
+
+    /// The position of the character in the numeral.
+    pub fn pos(&self) -> Position {
+        self.pos
+    }
+}
+
+/// Converts a string representing a Roman numeral into an integer, tolerating characters from
+/// other scripts that look like Roman numeral symbols.
+///
+/// Text produced by OCR, or copied from PDFs, often contains look-alike characters, such as the
+/// Cyrillic `Х` and `С` or the Greek `Ι`, in place of Latin letters. These are replaced by the
+/// symbols that they look like, and the numeral is then parsed as by [`roman_to_integer`]. Each
+/// replacement is reported along with the value, so that callers can tell whether the numeral was
+/// written correctly.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::roman_to_integer_confusable;
+///
+/// // A Cyrillic "Х" and a Greek "Ι"
+/// let (value, substitutions) = roman_to_integer_confusable("\u{0425}\u{0399}V").unwrap();
+/// assert_eq!(value, 14);
+/// assert_eq!(substitutions.len(), 2);
+/// assert_eq!(substitutions[0].replacement(), 'X');
+/// let (value, substitutions) = roman_to_integer_confusable("XIV").unwrap();
+/// assert!(value == 14 && substitutions.is_empty());
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer`] for the numeral with its characters replaced.
+/// Positions are relative to the input as it was given, and an [`InvalidCharacter`][a] is the
+/// character as it was given.
+///
+/// [a]: crate::RomanNumeralError::InvalidCharacter
+pub fn roman_to_integer_confusable(numeral: &str) -> Result<(u32, Vec<Substitution>)> {
+    let mapped = MappedNumeral::new(numeral, |ch, text| {
+        let replacement = CONFUSABLES.iter().find(|&&(confusable, _)| confusable == ch);
+        text.push(replacement.map_or(ch, |&(_, symbol)| symbol));
+    });
+    let value = mapped.parse()?;
+    let substitutions = mapped
+        .origins
+        .iter()
+        .zip(mapped.text.chars())
+        .filter(|&(&(_, _, found), replacement)| found != replacement)
+        .map(|(&(_, pos, found), replacement)| Substitution { found, replacement, pos })
+        .collect();
+    Ok((value, substitutions))
+}
+
+/// Converts a string representing a Roman numeral into an integer, after normalizing it to NFKC.
+///
+/// Normalization turns the full-width letters found in CJK text (e.g. `Ｘ`) and the Unicode number
+/// forms (e.g. `Ⅻ` or `ⅳ`) into ordinary letters, and ideographic spaces into ordinary spaces,
+/// before the numeral is parsed as by [`roman_to_integer`]. Requires the `unicode` feature.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::roman_to_integer_unicode;
+///
+/// assert_eq!(roman_to_integer_unicode("ＭＣＸＬＩＩ").unwrap(), 1142);
+/// assert_eq!(roman_to_integer_unicode("Ⅻ").unwrap(), 12);
+/// assert_eq!(roman_to_integer_unicode("ⅿⅽⅹⅼⅰⅰ").unwrap(), 1142);
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`roman_to_integer`] for the normalized numeral. Positions are
+/// relative to the input as it was given, and an [`InvalidCharacter`][a] is the character as it
+/// was given. A character which normalizes to several symbols, such as `Ⅻ`, is reported as
+/// the position of any error within them.
+///
+/// [a]: crate::RomanNumeralError::InvalidCharacter
+#[cfg(feature = "unicode")]
+pub fn roman_to_integer_unicode(numeral: &str) -> Result<u32> {
+    MappedNumeral::new(numeral, |ch, text| text.extend(std::iter::once(ch).nfkc())).parse()
+}
+
+/// A numeral with its non-ASCII characters rewritten, remembering where each character of the
+/// rewritten numeral came from, so that errors can be reported against the original.
+#[derive(Debug)]
+struct MappedNumeral {
+    text: String,
+    /// For each original character, its offset in `text`, its position, and the character.
+    origins: Vec<(usize, Position, char)>,
+}
+
+impl MappedNumeral {
+    fn new<F>(numeral: &str, map: F) -> Self
+    where
+        F: Fn(char, &mut String),
+    {
+        let mut text = String::with_capacity(numeral.len());
+        let mut origins = Vec::with_capacity(numeral.len());
+        let mut pos = Position::default();
+        for ch in numeral.chars() {
+            origins.push((text.len(), pos, ch));
+            if ch.is_ascii() {
+                text.push(ch);
+            } else {
+                map(ch, &mut text);
+            }
+            pos = Position::new(pos.byte + ch.len_utf8(), pos.char + 1);
+        }
+        MappedNumeral { text, origins }
+    }
+
+    fn parse(&self) -> Result<u32> {
+        roman_to_integer(&self.text).map_err(|error| self.restore(error))
+    }
+
+    /// Moves the position of `error` back to the original numeral.
+    fn restore(&self, error: RomanNumeralError) -> RomanNumeralError {
+        match error {
+            RomanNumeralError::InvalidCharacter { pos, .. } => {
+                let (pos, ch) = self.origin(pos);
+                RomanNumeralError::InvalidCharacter { ch, pos }
+            }
+            RomanNumeralError::InvalidRepetition { symbol, count, pos } => {
+                RomanNumeralError::InvalidRepetition { symbol, count, pos: self.origin(pos).0 }
+            }
+            RomanNumeralError::OutOfOrder { found, expected_max, pos } => {
+                RomanNumeralError::OutOfOrder { found, expected_max, pos: self.origin(pos).0 }
+            }
+            error => error,
+        }
+    }
+
+    /// The position and character in the original numeral from which the character at `pos` in
+    /// the rewritten numeral came.
+    fn origin(&self, pos: Position) -> (Position, char) {
+        let index = self.origins.partition_point(|&(byte, ..)| byte <= pos.byte);
+        let (_, pos, ch) = self.origins[index.saturating_sub(1)];
+        (pos, ch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "unicode")]
+    use crate::roman_to_integer_unicode;
+    use crate::{roman_to_integer_confusable, Position, RomanNumeralError};
+
+    #[test]
+    fn parse_numerals_with_confusables() {
+        let (value, substitutions) =
+            roman_to_integer_confusable("\u{041C}\u{0421}\u{0425}\u{0406}\u{0456}").unwrap();
+        assert_eq!(value, 1112);
+        let found: Vec<(char, char, usize)> = substitutions
+            .iter()
+            .map(|sub| (sub.found(), sub.replacement(), sub.pos().char))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ('\u{041C}', 'M', 0),
+                ('\u{0421}', 'C', 1),
+                ('\u{0425}', 'X', 2),
+                ('\u{0406}', 'I', 3),
+                ('\u{0456}', 'i', 4)
+            ]
+        );
+        assert_eq!(substitutions[1].pos(), Position::new(2, 1));
+        assert_eq!(roman_to_integer_confusable(" MCXLII "), Ok((1142, vec![])));
+    }
+
+    #[test]
+    fn report_errors_with_confusables_in_the_original_numeral() {
+        assert_eq!(
+            roman_to_integer_confusable("\u{0425}\u{0416}"),
+            Err(RomanNumeralError::InvalidCharacter { ch: '\u{0416}', pos: Position::new(2, 1) })
+        );
+        assert_eq!(
+            roman_to_integer_confusable("\u{0399}\u{0399}\u{0399}\u{0399}"),
+            Err(RomanNumeralError::InvalidRepetition {
+                symbol: "I",
+                count: 4,
+                pos: Position::new(6, 3)
+            })
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn parse_normalized_numerals() {
+        assert_eq!(roman_to_integer_unicode("MCXLII"), Ok(1142));
+        assert_eq!(roman_to_integer_unicode("\u{3000}ＭＭＸＸＩＶ\u{3000}"), Ok(2024));
+        assert_eq!(roman_to_integer_unicode("ｍｍｘｘｉｖ"), Ok(2024));
+        assert_eq!(roman_to_integer_unicode("ⅯⅯⅩⅩⅣ"), Ok(2024));
+        assert_eq!(roman_to_integer_unicode("MMXXⅣ"), Ok(2024));
+        assert_eq!(roman_to_integer_unicode("ⅯⅮⅭⅬⅩⅥ"), Ok(1666));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn report_errors_in_the_original_numeral() {
+        assert_eq!(
+            roman_to_integer_unicode("ＸＩＢ"),
+            Err(RomanNumeralError::InvalidCharacter { ch: 'Ｂ', pos: Position::new(6, 2) })
+        );
+        assert_eq!(
+            roman_to_integer_unicode("ⅫⅡ"),
+            Err(RomanNumeralError::InvalidRepetition {
+                symbol: "I",
+                count: 4,
+                pos: Position::new(3, 1)
+            })
+        );
+        assert_eq!(
+            roman_to_integer_unicode("Ｉ Ｍ"),
+            Err(RomanNumeralError::InvalidCharacter { ch: ' ', pos: Position::new(3, 1) })
+        );
+        assert_eq!(roman_to_integer_unicode("\u{3000}"), Err(RomanNumeralError::EmptyString));
+    }
+}
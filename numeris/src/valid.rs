@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use super::{roman_to_integer, Result, RomanNumeral, RomanNumeralError};
+
+/// A view of a valid Roman numeral within a borrowed string.
+///
+/// A `ValidRoman` is created by validating a string once, as by [`roman_to_integer`], after which
+/// its value and canonical form are available without parsing or copying it again. This allows a
+/// parser to hold on to the numerals it finds in a large input without allocating for each one.
+///
+/// Leading and trailing whitespace is trimmed, but the case of the numeral is preserved. Two
+/// `ValidRoman`s are equal, and are ordered, according to their values, so `XIV` and `xiv` are
+/// equal.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::ValidRoman;
+///
+/// let input = "Chapter xlii";
+/// let rn = ValidRoman::new(&input[8..]).unwrap();
+/// assert_eq!(rn.value(), 42);
+/// assert_eq!(rn.as_str(), "xlii");
+/// assert_eq!(rn.canonical(), "XLII");
+/// assert_eq!(rn.to_string(), "xlii");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ValidRoman<'a> {
+    numeral: &'a str,
+    value: u32,
+}
+
+impl<'a> ValidRoman<'a> {
+    /// Validates `numeral`, keeping a reference to it.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by [`roman_to_integer`].
+    pub fn new(numeral: &'a str) -> Result<Self> {
+        let value = roman_to_integer(numeral)?;
+        Ok(ValidRoman { numeral: numeral.trim(), value })
+    }
+
+    /// The numeral, as it appears in the original string, without surrounding whitespace.
+    pub fn as_str(&self) -> &'a str {
+        self.numeral
+    }
+
+    /// The value of the numeral.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// The canonical, upper-case, form of the numeral.
+    ///
+    /// This borrows from the original string if the numeral is already in upper-case, and only
+    /// allocates if it is not.
+    pub fn canonical(&self) -> Cow<'a, str> {
+        if self.numeral.bytes().any(|b| b.is_ascii_lowercase()) {
+            Cow::Owned(self.numeral.to_ascii_uppercase())
+        } else {
+            Cow::Borrowed(self.numeral)
+        }
+    }
+}
+
+impl fmt::Display for ValidRoman<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.numeral)
+    }
+}
+
+impl AsRef<str> for ValidRoman<'_> {
+    fn as_ref(&self) -> &str {
+        self.numeral
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ValidRoman<'a> {
+    type Error = RomanNumeralError;
+
+    fn try_from(numeral: &'a str) -> Result<Self> {
+        ValidRoman::new(numeral)
+    }
+}
+
+impl From<ValidRoman<'_>> for RomanNumeral {
+    fn from(numeral: ValidRoman<'_>) -> Self {
+        RomanNumeral::new(numeral.value).expect("a valid numeral is always in range")
+    }
+}
+
+impl From<ValidRoman<'_>> for u32 {
+    fn from(numeral: ValidRoman<'_>) -> Self {
+        numeral.value
+    }
+}
+
+impl PartialEq for ValidRoman<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for ValidRoman<'_> {}
+
+impl Hash for ValidRoman<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl PartialOrd for ValidRoman<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ValidRoman<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::convert::TryFrom;
+
+    use crate::{
+        integer_to_roman, RomanNumeral, RomanNumeralError, ValidRoman, MAX_VALUE, MIN_VALUE,
+    };
+
+    #[test]
+    fn validate_all_numerals() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let numeral = integer_to_roman(val).unwrap();
+            let rn = ValidRoman::new(&numeral).unwrap();
+            assert_eq!(rn.value(), val);
+            assert_eq!(RomanNumeral::from(rn).value(), val);
+            assert!(std::ptr::eq(rn.as_str(), numeral.as_str()));
+        }
+    }
+
+    #[test]
+    fn borrow_from_original_string() {
+        let input = String::from("  mcmxcix\n");
+        let rn = ValidRoman::try_from(input.as_str()).unwrap();
+        assert_eq!(rn.as_str(), "mcmxcix");
+        assert_eq!(rn.to_string(), "mcmxcix");
+        assert_eq!(rn.canonical(), Cow::<str>::Owned(String::from("MCMXCIX")));
+        let rn = ValidRoman::new("XIV").unwrap();
+        assert!(matches!(rn.canonical(), Cow::Borrowed("XIV")));
+    }
+
+    #[test]
+    fn compare_by_value() {
+        let lower = ValidRoman::new("xiv").unwrap();
+        let upper = ValidRoman::new(" XIV ").unwrap();
+        assert_eq!(lower, upper);
+        assert!(ValidRoman::new("IX").unwrap() < ValidRoman::new("X").unwrap());
+        assert_eq!(u32::from(lower), 14);
+    }
+
+    #[test]
+    fn reject_invalid_numerals() {
+        assert_eq!(ValidRoman::new(" "), Err(RomanNumeralError::EmptyString));
+        assert!(ValidRoman::new("IIII").is_err());
+        assert!(ValidRoman::try_from("XIV!").is_err());
+    }
+}
@@ -0,0 +1,57 @@
+//! JavaScript bindings, for use with `wasm-pack`. Requires the `wasm` feature.
+//!
+//! The functions in this module are exported to JavaScript with camel-case names, and any error
+//! is thrown as a JavaScript `Error` carrying the error's message:
+//!
+//! ```js
+//! import { integerToRoman, romanToInteger } from "numeris";
+//!
+//! integerToRoman(1142); // "MCXLII"
+//! romanToInteger("xlii"); // 42
+//! romanToInteger("XIIII"); // throws Error("I is repeated 4 times in a row")
+//! ```
+//!
+//! The crate is only built as a Rust library, so the WebAssembly module is built by passing the
+//! crate type to `cargo rustc`, and then generating its JavaScript glue with `wasm-bindgen`:
+//!
+//! ```sh
+//! cargo rustc -p numeris --release --target wasm32-unknown-unknown --features wasm \
+//!     --crate-type cdylib
+//! wasm-bindgen --out-dir pkg target/wasm32-unknown-unknown/release/numeris.wasm
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+/// Converts an integer into a string representing a Roman numeral, as by
+/// [`integer_to_roman`][crate::integer_to_roman]. Exported as `integerToRoman`.
+///
+/// JavaScript numbers that are not whole numbers cannot be converted, and are reported as
+/// [`Unparsable`][crate::RomanNumeralError::Unparsable].
+#[wasm_bindgen(js_name = integerToRoman)]
+pub fn integer_to_roman(val: f64) -> Result<String, JsError> {
+    if val.fract() != 0.0 || !val.is_finite() {
+        return Err(crate::RomanNumeralError::Unparsable(val.to_string()).into());
+    }
+    Ok(crate::integer_to_roman(val as i64)?)
+}
+
+/// Converts a string representing a Roman numeral into an integer, as by
+/// [`roman_to_integer`][crate::roman_to_integer]. Exported as `romanToInteger`.
+#[wasm_bindgen(js_name = romanToInteger)]
+pub fn roman_to_integer(numeral: &str) -> Result<u32, JsError> {
+    Ok(crate::roman_to_integer(numeral)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{integer_to_roman, roman_to_integer};
+
+    // Errors can only be created when running in JavaScript, so only successful conversions can
+    // be tested natively.
+    #[test]
+    fn convert_from_javascript_values() {
+        assert_eq!(integer_to_roman(1142.0).ok(), Some(String::from("MCXLII")));
+        assert_eq!(integer_to_roman(3999.0).ok(), Some(String::from("MMMCMXCIX")));
+        assert_eq!(roman_to_integer(" xlii ").ok(), Some(42));
+    }
+}
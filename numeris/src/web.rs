@@ -0,0 +1,160 @@
+//! Support for using [`RomanNumeral`]s in web frameworks.
+//!
+//! With the `serde` feature enabled, a `RomanNumeral` is serialized as its numeral, and can be
+//! deserialized from any string accepted by [`roman_to_integer`][crate::roman_to_integer]. As
+//! `axum` and `actix-web` use `serde` to extract path segments and query parameters, this is all
+//! they need: a route such as `/chapters/{chapter}` can take a `Path<RomanNumeral>` directly, and
+//! an invalid numeral is rejected with the reason that it is invalid. `axum` rejects it with a
+//! 400 response, as does `actix-web` for a query parameter; `actix-web` rejects a path segment
+//! with a 404 response unless its `PathConfig` says otherwise.
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use numeris::RomanNumeral;
+//! use serde_json::json;
+//!
+//! let rn: RomanNumeral = serde_json::from_value(json!("xlii")).unwrap();
+//! assert_eq!(rn.value(), 42);
+//! assert_eq!(serde_json::to_value(rn).unwrap(), json!("XLII"));
+//! # }
+//! ```
+//!
+//! With the `rocket` feature enabled, a `RomanNumeral` can be used as a path segment or query
+//! parameter in a `rocket` route. If the numeral is invalid, the route is skipped, as for any
+//! other parameter; to respond with the error instead, take a
+//! `Result<RomanNumeral, RomanNumeralError>`, as a [`RomanNumeralError`] responds with a 400
+//! response describing the error:
+//!
+//! ```ignore
+//! #[get("/chapters/<chapter>")]
+//! fn chapter(
+//!     chapter: Result<RomanNumeral, RomanNumeralError>,
+//! ) -> Result<String, RomanNumeralError> {
+//!     Ok(format!("Chapter {}", chapter?.value()))
+//! }
+//! ```
+
+#[cfg(feature = "serde")]
+use std::fmt;
+
+#[cfg(feature = "rocket")]
+use rocket::form::{self, FromFormField, ValueField};
+#[cfg(feature = "rocket")]
+use rocket::http::Status;
+#[cfg(feature = "rocket")]
+use rocket::request::{FromParam, Request};
+#[cfg(feature = "rocket")]
+use rocket::response::{self, Responder};
+#[cfg(feature = "serde")]
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, Serializer};
+
+use super::RomanNumeral;
+#[cfg(feature = "rocket")]
+use super::RomanNumeralError;
+
+#[cfg(feature = "serde")]
+impl Serialize for RomanNumeral {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RomanNumeral {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(RomanNumeralVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct RomanNumeralVisitor;
+
+#[cfg(feature = "serde")]
+impl Visitor<'_> for RomanNumeralVisitor {
+    type Value = RomanNumeral;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a Roman numeral")
+    }
+
+    fn visit_str<E: de::Error>(self, numeral: &str) -> Result<Self::Value, E> {
+        numeral.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "rocket")]
+impl<'a> FromParam<'a> for RomanNumeral {
+    type Error = RomanNumeralError;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        param.parse()
+    }
+}
+
+#[cfg(feature = "rocket")]
+#[rocket::async_trait]
+impl<'v> FromFormField<'v> for RomanNumeral {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        field
+            .value
+            .parse()
+            .map_err(|e: RomanNumeralError| form::Error::validation(e.to_string()).into())
+    }
+}
+
+#[cfg(feature = "rocket")]
+impl<'r> Responder<'r, 'static> for RomanNumeralError {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        (Status::BadRequest, self.to_string()).respond_to(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rocket")]
+    use rocket::form::Form;
+    #[cfg(feature = "rocket")]
+    use rocket::request::FromParam;
+    #[cfg(feature = "serde")]
+    use serde_json::json;
+
+    use crate::RomanNumeral;
+    #[cfg(feature = "rocket")]
+    use crate::RomanNumeralError;
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_as_numeral() {
+        let rn = RomanNumeral::new(1142).unwrap();
+        assert_eq!(serde_json::to_value(rn).unwrap(), json!("MCXLII"));
+        assert_eq!(serde_json::from_value::<RomanNumeral>(json!(" mcxlii ")).unwrap(), rn);
+        assert_eq!(serde_json::to_string(&[rn]).unwrap(), r#"["MCXLII"]"#);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn reject_invalid_numerals() {
+        let error = serde_json::from_value::<RomanNumeral>(json!("IIII")).unwrap_err();
+        assert_eq!(error.to_string(), "I is repeated 4 times in a row");
+        let error = serde_json::from_value::<RomanNumeral>(json!(42)).unwrap_err();
+        assert_eq!(error.to_string(), "invalid type: integer `42`, expected a Roman numeral");
+    }
+
+    #[test]
+    #[cfg(feature = "rocket")]
+    fn parse_rocket_parameters() {
+        assert_eq!(RomanNumeral::from_param("XLII"), Ok(RomanNumeral::new(42).unwrap()));
+        assert_eq!(RomanNumeral::from_param(""), Err(RomanNumeralError::EmptyString));
+        #[derive(Debug, rocket::FromForm)]
+        struct Query {
+            chapter: RomanNumeral,
+        }
+        let query: Query = Form::parse("chapter=xiv").unwrap();
+        assert_eq!(query.chapter.value(), 14);
+        let errors = Form::<Query>::parse("chapter=XIIII").unwrap_err();
+        assert_eq!(errors[0].to_string(), "I is repeated 4 times in a row");
+    }
+}
@@ -0,0 +1,252 @@
+use std::convert::TryInto;
+
+use super::itor::{check_range, to_u32};
+use super::{Result, RomanNumeralError};
+
+const UNITS: [&str; 10] =
+    ["", "unus", "duo", "tres", "quattuor", "quinque", "sex", "septem", "octo", "novem"];
+const TEENS: [&str; 10] = [
+    "decem",
+    "undecim",
+    "duodecim",
+    "tredecim",
+    "quattuordecim",
+    "quindecim",
+    "sedecim",
+    "septendecim",
+    "duodeviginti",
+    "undeviginti",
+];
+const TENS: [&str; 10] = [
+    "",
+    "decem",
+    "viginti",
+    "triginta",
+    "quadraginta",
+    "quinquaginta",
+    "sexaginta",
+    "septuaginta",
+    "octoginta",
+    "nonaginta",
+];
+const HUNDREDS: [&str; 10] = [
+    "",
+    "centum",
+    "ducenti",
+    "trecenti",
+    "quadringenti",
+    "quingenti",
+    "sescenti",
+    "septingenti",
+    "octingenti",
+    "nongenti",
+];
+const SUBTRACTIVE_TENS: [[&str; 2]; 7] = [
+    ["duodetriginta", "undetriginta"],
+    ["duodequadraginta", "undequadraginta"],
+    ["duodequinquaginta", "undequinquaginta"],
+    ["duodesexaginta", "undesexaginta"],
+    ["duodeseptuaginta", "undeseptuaginta"],
+    ["duodeoctoginta", "undeoctoginta"],
+    ["duodenonaginta", "undenonaginta"],
+];
+const THOUSANDS: [&str; 4] = ["", "mille", "duo milia", "tria milia"];
+
+/// Converts an integer into its Latin cardinal number words.
+///
+/// The input must be in the same range, and of the same types, as accepted by
+/// [`integer_to_roman`][crate::integer_to_roman]. The words are returned in lower-case, separated
+/// by single spaces. Numbers ending in 8 or 9 use the subtractive forms (e.g. _duodeviginti_ for
+/// 18, _undetriginta_ for 29), except for 98 and 99, which are written additively.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::integer_to_latin_words;
+///
+/// assert_eq!(integer_to_latin_words(1142).unwrap(), "mille centum quadraginta duo");
+/// assert_eq!(integer_to_latin_words(2019).unwrap(), "duo milia undeviginti");
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by [`integer_to_roman`][crate::integer_to_roman].
+pub fn integer_to_latin_words<T>(val: T) -> Result<String>
+where
+    T: TryInto<u32> + PartialOrd + Default,
+{
+    let val = to_u32(val)?;
+    check_range(val)?;
+    let digit = |place: u32| (val / place % 10) as usize;
+    let (tens, units) = (digit(10), digit(1));
+    let mut words = vec![THOUSANDS[digit(1000)], HUNDREDS[digit(100)]];
+    if tens == 1 {
+        words.push(TEENS[units]);
+    } else if (2..=8).contains(&tens) && units >= 8 {
+        words.push(SUBTRACTIVE_TENS[tens - 2][units - 8]);
+    } else {
+        words.push(TENS[tens]);
+        words.push(UNITS[units]);
+    }
+    words.retain(|word| !word.is_empty());
+    Ok(words.join(" "))
+}
+
+/// Converts Latin cardinal number words into an integer.
+///
+/// This is the inverse of [`integer_to_latin_words`]. Case is ignored, and words may be separated
+/// by any amount of whitespace. The variant spelling _millia_ is accepted for _milia_.
+///
+/// # Examples
+///
+/// ```
+/// use numeris::latin_words_to_integer;
+///
+/// assert_eq!(latin_words_to_integer("mille nongenti octoginta quattuor").unwrap(), 1984);
+/// assert_eq!(latin_words_to_integer("  Duo   Milia\tUNDEVIGINTI ").unwrap(), 2019);
+/// ```
+///
+/// # Errors
+///
+/// | `RomanNumeralError` | Reason |
+/// | ----------------------- | ------ |
+/// | [`Unparsable`][a] | `words` cannot be parsed as a Latin number |
+/// | [`EmptyString`][b] |  `words` is an empty string or contains only whitespace |
+///
+/// [a]: crate::RomanNumeralError::Unparsable
+/// [b]: crate::RomanNumeralError::EmptyString
+pub fn latin_words_to_integer(words: &str) -> Result<u32> {
+    let normalized = words.trim().to_lowercase();
+    if normalized.is_empty() {
+        return Err(RomanNumeralError::EmptyString);
+    }
+    let tokens: Vec<&str> = normalized.split_whitespace().collect();
+    let (mut result, consumed) = match &tokens[..] {
+        ["mille", ..] => (1000, 1),
+        ["duo", "milia", ..] | ["duo", "millia", ..] => (2000, 2),
+        ["tria", "milia", ..] | ["tria", "millia", ..] => (3000, 2),
+        _ => (0, 0),
+    };
+    let mut rest = &tokens[consumed..];
+    result += take_word(&mut rest, &HUNDREDS).unwrap_or(0) as u32 * 100;
+    if let Some(units) = take_word(&mut rest, &TEENS) {
+        result += 10 + units as u32;
+    } else if let Some(pos) = take_word(&mut rest, &SUBTRACTIVE_TENS.concat()) {
+        result += (pos as u32 / 2 + 3) * 10 - 2 + pos as u32 % 2;
+    } else {
+        result += take_word(&mut rest, &TENS).unwrap_or(0) as u32 * 10;
+        result += take_word(&mut rest, &UNITS).unwrap_or(0) as u32;
+    }
+    if rest.is_empty() {
+        Ok(result)
+    } else {
+        Err(RomanNumeralError::Unparsable(normalized))
+    }
+}
+
+fn take_word(rest: &mut &[&str], table: &[&str]) -> Option<usize> {
+    let pos = table.iter().position(|word| !word.is_empty() && rest.first() == Some(word))?;
+    *rest = &rest[1..];
+    Some(pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        integer_to_latin_words, latin_words_to_integer, RomanNumeralError, MAX_VALUE, MIN_VALUE,
+    };
+
+    #[test]
+    fn parse_latin_words() {
+        assert_eq!(latin_words_to_integer("unus").unwrap(), 1);
+        assert_eq!(latin_words_to_integer("undeviginti").unwrap(), 19);
+        assert_eq!(latin_words_to_integer("duodenonaginta").unwrap(), 88);
+        assert_eq!(latin_words_to_integer("nonaginta novem").unwrap(), 99);
+        assert_eq!(latin_words_to_integer("QUADRINGENTI decem").unwrap(), 410);
+        assert_eq!(latin_words_to_integer("tria millia septem").unwrap(), 3007);
+        assert_eq!(latin_words_to_integer("mille centum quadraginta duo").unwrap(), 1142);
+    }
+
+    #[test]
+    fn round_trip_all_values() {
+        for val in MIN_VALUE..=MAX_VALUE {
+            let words = integer_to_latin_words(val).unwrap();
+            assert_eq!(latin_words_to_integer(&words), Ok(val), "{}", words);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_latin_words() {
+        assert_eq!(latin_words_to_integer(" \n"), Err(RomanNumeralError::EmptyString));
+        let invalid_values = [
+            "decem unus",
+            "centum mille",
+            "duo duo",
+            "milia",
+            "viginti viginti",
+            "quinque sex",
+            "X",
+        ];
+        for val in invalid_values.iter() {
+            match latin_words_to_integer(val) {
+                Err(RomanNumeralError::Unparsable(_)) => (),
+                Err(e) => panic!("wrong kind of error: {:?}", e),
+                Ok(int_val) => panic!("unexpected ok result: {} = {}", val, int_val),
+            }
+        }
+    }
+
+    #[test]
+    fn reject_values_out_of_range() {
+        assert_eq!(
+            integer_to_latin_words(MIN_VALUE - 1),
+            Err(RomanNumeralError::ValueTooSmall(MIN_VALUE - 1))
+        );
+        assert_eq!(
+            integer_to_latin_words(MAX_VALUE + 1),
+            Err(RomanNumeralError::ValueTooLarge(MAX_VALUE + 1))
+        );
+        assert_eq!(integer_to_latin_words(-5i64), Err(RomanNumeralError::ValueTooSmall(0)));
+        assert_eq!(
+            integer_to_latin_words(u64::MAX),
+            Err(RomanNumeralError::ValueTooLarge(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn accept_any_integer_type() {
+        assert_eq!(integer_to_latin_words(42u8).unwrap(), "quadraginta duo");
+        assert_eq!(integer_to_latin_words(42i16).unwrap(), "quadraginta duo");
+        assert_eq!(integer_to_latin_words(42usize).unwrap(), "quadraginta duo");
+    }
+
+    #[test]
+    fn convert_units_and_teens() {
+        assert_eq!(integer_to_latin_words(1).unwrap(), "unus");
+        assert_eq!(integer_to_latin_words(4).unwrap(), "quattuor");
+        assert_eq!(integer_to_latin_words(10).unwrap(), "decem");
+        assert_eq!(integer_to_latin_words(13).unwrap(), "tredecim");
+        assert_eq!(integer_to_latin_words(18).unwrap(), "duodeviginti");
+        assert_eq!(integer_to_latin_words(19).unwrap(), "undeviginti");
+    }
+
+    #[test]
+    fn convert_tens() {
+        assert_eq!(integer_to_latin_words(20).unwrap(), "viginti");
+        assert_eq!(integer_to_latin_words(21).unwrap(), "viginti unus");
+        assert_eq!(integer_to_latin_words(28).unwrap(), "duodetriginta");
+        assert_eq!(integer_to_latin_words(89).unwrap(), "undenonaginta");
+        assert_eq!(integer_to_latin_words(98).unwrap(), "nonaginta octo");
+        assert_eq!(integer_to_latin_words(99).unwrap(), "nonaginta novem");
+    }
+
+    #[test]
+    fn convert_hundreds_and_thousands() {
+        assert_eq!(integer_to_latin_words(100).unwrap(), "centum");
+        assert_eq!(integer_to_latin_words(648).unwrap(), "sescenti duodequinquaginta");
+        assert_eq!(integer_to_latin_words(1000).unwrap(), "mille");
+        assert_eq!(integer_to_latin_words(1984).unwrap(), "mille nongenti octoginta quattuor");
+        assert_eq!(integer_to_latin_words(3000).unwrap(), "tria milia");
+        assert_eq!(integer_to_latin_words(3999).unwrap(), "tria milia nongenti nonaginta novem");
+    }
+}